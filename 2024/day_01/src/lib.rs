@@ -0,0 +1,429 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    io::{self, BufRead, Write},
+};
+
+/// A line of the input which is not a row of location IDs.
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    /// The row has another number of columns than the first row.
+    Ragged {
+        line: usize,
+        columns: usize,
+        expected: usize,
+    },
+    InvalidId {
+        line: usize,
+        value: String,
+    },
+    Unreadable {
+        line: usize,
+        message: String,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Ragged {
+                line,
+                columns,
+                expected,
+            } => write!(
+                f,
+                "line {}: {} columns instead of {}",
+                line, columns, expected
+            ),
+            ParseError::InvalidId { line, value } => {
+                write!(f, "line {}: invalid location ID '{}'", line, value)
+            }
+            ParseError::Unreadable { line, message } => {
+                write!(f, "line {}: cannot read: {}", line, message)
+            }
+        }
+    }
+}
+
+/// Reads the whitespace separated columns of location IDs line by line. Empty lines are
+/// skipped, every other line must have as many columns as the first one.
+pub fn read_columns(mut reader: impl BufRead) -> Result<Vec<Vec<i64>>, Vec<ParseError>> {
+    let mut columns: Vec<Vec<i64>> = vec![];
+    let mut errors = vec![];
+    let mut line = String::new();
+    let mut row = vec![];
+    for number in 1.. {
+        line.clear();
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(error) => {
+                errors.push(ParseError::Unreadable {
+                    line: number,
+                    message: error.to_string(),
+                });
+                break;
+            }
+        }
+
+        row.clear();
+        let mut values = 0;
+        for value in line.split_ascii_whitespace() {
+            values += 1;
+            match value.parse() {
+                Ok(id) => row.push(id),
+                Err(_) => errors.push(ParseError::InvalidId {
+                    line: number,
+                    value: value.to_string(),
+                }),
+            }
+        }
+        if values == 0 {
+            continue;
+        }
+        if columns.is_empty() {
+            columns = vec![vec![]; values];
+        }
+        if values != columns.len() {
+            errors.push(ParseError::Ragged {
+                line: number,
+                columns: values,
+                expected: columns.len(),
+            });
+        } else if row.len() == columns.len() {
+            columns
+                .iter_mut()
+                .zip(row.iter())
+                .for_each(|(column, id)| column.push(*id));
+        }
+    }
+    if errors.is_empty() {
+        Ok(columns)
+    } else {
+        Err(errors)
+    }
+}
+
+pub fn get_columns(input: &str) -> Result<Vec<Vec<i64>>, Vec<ParseError>> {
+    read_columns(input.as_bytes())
+}
+
+fn get_lists(input: &str) -> (Vec<i64>, Vec<i64>) {
+    let mut columns = get_columns(input)
+        .unwrap_or_else(|errors| panic!("Invalid input, {}", errors[0]))
+        .into_iter();
+    match (columns.next(), columns.next()) {
+        (Some(l1), Some(l2)) => (l1, l2),
+        _ => panic!("The input needs two lists."),
+    }
+}
+
+/// Sum of the distances between the sorted lists, `None` if it does not fit into an `u64`.
+pub fn total_distance(l1: &[i64], l2: &[i64]) -> Option<u64> {
+    let mut l1 = l1.to_vec();
+    let mut l2 = l2.to_vec();
+    l1.sort_unstable();
+    l2.sort_unstable();
+
+    l1.iter()
+        .zip(l2.iter())
+        .try_fold(0u64, |sum, (i1, i2)| sum.checked_add(i1.abs_diff(*i2)))
+}
+
+fn count_occurrences(list: &[i64]) -> HashMap<i64, i64> {
+    let mut occurrences = HashMap::new();
+    for id in list {
+        *occurrences.entry(*id).or_default() += 1;
+    }
+    occurrences
+}
+
+/// Sum of the IDs of `l1` multiplied by their number of occurrences in `l2`, `None` if it
+/// does not fit into an `i64`.
+pub fn similarity_score(l1: &[i64], l2: &[i64]) -> Option<i64> {
+    let occurrences = count_occurrences(l2);
+    l1.iter().try_fold(0i64, |sum, id| {
+        let occurrences = occurrences.get(id).copied().unwrap_or(0);
+        sum.checked_add(id.checked_mul(occurrences)?)
+    })
+}
+
+/// Two IDs which are paired up after sorting both lists.
+pub struct Pair {
+    /// Position of the IDs in the sorted lists, starting at 1.
+    pub rank: usize,
+    pub left: i64,
+    pub right: i64,
+    pub distance: u64,
+    /// Total distance of the pairs up to this one, `None` after an overflow.
+    pub running_total: Option<u64>,
+}
+
+pub fn matched_pairs(l1: &[i64], l2: &[i64]) -> Vec<Pair> {
+    let mut l1 = l1.to_vec();
+    let mut l2 = l2.to_vec();
+    l1.sort_unstable();
+    l2.sort_unstable();
+
+    let mut running_total = Some(0u64);
+    l1.iter()
+        .zip(l2.iter())
+        .enumerate()
+        .map(|(index, (left, right))| {
+            let distance = left.abs_diff(*right);
+            running_total = running_total.and_then(|total| total.checked_add(distance));
+            Pair {
+                rank: index + 1,
+                left: *left,
+                right: *right,
+                distance,
+                running_total,
+            }
+        })
+        .collect()
+}
+
+/// Writes the pairs as tab separated values with a header line.
+pub fn write_pairs(mut writer: impl Write, pairs: &[Pair]) -> io::Result<()> {
+    writeln!(writer, "rank\tleft\tright\tdistance\trunning_total")?;
+    for pair in pairs {
+        let running_total = match pair.running_total {
+            Some(total) => total.to_string(),
+            None => "overflow".to_string(),
+        };
+        writeln!(
+            writer,
+            "{}\t{}\t{}\t{}\t{}",
+            pair.rank, pair.left, pair.right, pair.distance, running_total
+        )?;
+    }
+    // A buffered writer would lose an error of the last write when it is dropped.
+    writer.flush()
+}
+
+/// The part of the similarity score which is due to one ID.
+pub struct Contribution {
+    pub id: i64,
+    /// Occurrences of the ID in the left list.
+    pub left: i64,
+    /// Occurrences of the ID in the right list.
+    pub right: i64,
+    /// `None` if the contribution does not fit into an `i64`.
+    pub score: Option<i64>,
+}
+
+/// Contributions of the IDs of `l1` which occur in `l2`, largest first.
+pub fn similarity_contributions(l1: &[i64], l2: &[i64]) -> Vec<Contribution> {
+    let right = count_occurrences(l2);
+
+    let mut contributions = count_occurrences(l1)
+        .into_iter()
+        .filter_map(|(id, left)| {
+            let right = *right.get(&id)?;
+            Some(Contribution {
+                id,
+                left,
+                right,
+                score: id
+                    .checked_mul(left)
+                    .and_then(|score| score.checked_mul(right)),
+            })
+        })
+        .collect::<Vec<_>>();
+    // Overflowing contributions are the largest ones.
+    contributions.sort_by_key(|contribution| {
+        (
+            contribution.score.is_some(),
+            std::cmp::Reverse(contribution.score),
+            contribution.id,
+        )
+    });
+    contributions
+}
+
+/// Total distance and similarity score of every pair of columns.
+pub struct Comparison {
+    /// `None` marks an overflow.
+    pub total_distances: Vec<Vec<Option<u64>>>,
+    /// The similarity score of the IDs of the row column, counted in the other column.
+    pub similarity_scores: Vec<Vec<Option<i64>>>,
+}
+
+fn matrix<T>(columns: &[Vec<i64>], measure: fn(&[i64], &[i64]) -> T) -> Vec<Vec<T>> {
+    columns
+        .iter()
+        .map(|row| columns.iter().map(|column| measure(row, column)).collect())
+        .collect()
+}
+
+pub fn compare_columns(columns: &[Vec<i64>]) -> Comparison {
+    Comparison {
+        total_distances: matrix(columns, total_distance),
+        similarity_scores: matrix(columns, similarity_score),
+    }
+}
+
+fn write_matrix<T: Display>(
+    f: &mut fmt::Formatter<'_>,
+    title: &str,
+    matrix: &[Vec<Option<T>>],
+) -> fmt::Result {
+    let cells = matrix
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|value| match value {
+                    Some(value) => value.to_string(),
+                    None => "overflow".to_string(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let width = cells
+        .iter()
+        .flatten()
+        .map(|cell| cell.len())
+        .chain([matrix.len().to_string().len()])
+        .max()
+        .unwrap_or(1);
+    let label_width = matrix.len().to_string().len();
+
+    writeln!(f, "{}", title)?;
+    write!(f, "{:label_width$}", "", label_width = label_width)?;
+    for column in 1..=matrix.len() {
+        write!(f, "  {:>width$}", column, width = width)?;
+    }
+    writeln!(f)?;
+    for (row, cells) in cells.iter().enumerate() {
+        write!(f, "{:>label_width$}", row + 1, label_width = label_width)?;
+        for cell in cells {
+            write!(f, "  {:>width$}", cell, width = width)?;
+        }
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_matrix(f, "Total distance", &self.total_distances)?;
+        writeln!(f)?;
+        write_matrix(
+            f,
+            "Similarity score (row IDs counted in the column)",
+            &self.similarity_scores,
+        )
+    }
+}
+
+pub fn part_1(input: &str) -> String {
+    let (l1, l2) = get_lists(input);
+    total_distance(&l1, &l2)
+        .expect("The total distance overflows.")
+        .to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let (l1, l2) = get_lists(input);
+    similarity_score(&l1, &l2)
+        .expect("The similarity score overflows.")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_columns() {
+        let columns = get_columns("3 4 3\n4 3 1\n\n2 5 3\n").unwrap();
+        let comparison = compare_columns(&columns);
+        assert_eq!(
+            vec![Some(0), Some(3), Some(2)],
+            comparison.total_distances[0]
+        );
+        assert_eq!(
+            vec![Some(3), Some(0), Some(5)],
+            comparison.total_distances[1]
+        );
+        assert_eq!(
+            vec![Some(9), Some(7), Some(6)],
+            comparison.similarity_scores[0]
+        );
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        assert_eq!(
+            Err(vec![
+                ParseError::Ragged {
+                    line: 2,
+                    columns: 1,
+                    expected: 2
+                },
+                ParseError::InvalidId {
+                    line: 3,
+                    value: "x".to_string()
+                },
+                ParseError::Ragged {
+                    line: 4,
+                    columns: 3,
+                    expected: 2
+                },
+            ]),
+            get_columns("1 2\n3\n4 x\n5 6 7\n")
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let big = i64::MAX / 2;
+        assert_eq!(None, similarity_score(&[big], &[big, big, big]));
+        assert_eq!(Some(big), similarity_score(&[big, 1], &[big]));
+        assert_eq!(None, total_distance(&[i64::MIN, i64::MIN], &[i64::MAX, 0]));
+    }
+
+    #[test]
+    fn test_matched_pairs() {
+        let (l1, l2) = get_lists(include_str!("../input/demo.txt"));
+        let pairs = matched_pairs(&l1, &l2);
+        assert_eq!(
+            vec![(1, 1, 2, Some(2)), (2, 2, 1, Some(3)), (6, 4, 5, Some(11))],
+            [0, 1, 5]
+                .iter()
+                .map(|index| {
+                    let pair = &pairs[*index];
+                    (pair.rank, pair.left, pair.distance, pair.running_total)
+                })
+                .collect::<Vec<_>>()
+        );
+
+        let contributions = similarity_contributions(&l1, &l2);
+        assert_eq!(
+            vec![(3, Some(27)), (4, Some(4))],
+            contributions
+                .iter()
+                .map(|contribution| (contribution.id, contribution.score))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    /// A file on a full disk.
+    struct FullDisk;
+
+    impl Write for FullDisk {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::StorageFull.into())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_pairs_error() {
+        let pairs = matched_pairs(&[3, 4], &[4, 3]);
+        let written = write_pairs(io::BufWriter::new(FullDisk), &pairs);
+        assert_eq!(io::ErrorKind::StorageFull, written.unwrap_err().kind());
+    }
+}
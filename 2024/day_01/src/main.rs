@@ -0,0 +1,118 @@
+use std::{
+    env,
+    fs::File,
+    io::{BufReader, BufWriter, Read},
+    process,
+};
+
+const USAGE: &str = "Usage: day_2024_01 [--matrix] [--input FILE] [--pairs FILE] [--top COUNT]";
+
+struct Options {
+    matrix: bool,
+    input: Option<String>,
+    pairs: Option<String>,
+    top: Option<usize>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        matrix: false,
+        input: None,
+        pairs: None,
+        top: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--matrix" => options.matrix = true,
+            "--input" => options.input = Some(args.next().ok_or("--input expects a file.")?),
+            "--pairs" => options.pairs = Some(args.next().ok_or("--pairs expects a file.")?),
+            "--top" => {
+                let top = args.next().ok_or("--top expects a count.")?;
+                options.top = Some(
+                    top.parse()
+                        .map_err(|_| format!("Invalid count '{}'.", top))?,
+                );
+            }
+            arg => return Err(format!("Unknown argument '{}'.", arg)),
+        }
+    }
+    Ok(options)
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    // Large exports are parsed while they are read instead of being loaded as a whole.
+    let reader: Box<dyn Read> = match &options.input {
+        Some(path) => Box::new(File::open(path).unwrap_or_else(|error| {
+            eprintln!("Cannot read {}: {}", path, error);
+            process::exit(1);
+        })),
+        None => Box::new(include_str!("../input/input.txt").as_bytes()),
+    };
+    let columns = match day_2024_01::read_columns(BufReader::new(reader)) {
+        Ok(columns) => columns,
+        Err(errors) => {
+            for error in errors {
+                eprintln!("{}", error);
+            }
+            process::exit(1);
+        }
+    };
+
+    if options.matrix {
+        print!("{}", day_2024_01::compare_columns(&columns));
+        return;
+    }
+
+    let [l1, l2, ..] = columns.as_slice() else {
+        eprintln!("The input needs two lists.");
+        process::exit(1);
+    };
+    match day_2024_01::total_distance(l1, l2) {
+        Some(distance) => println!("The total distance between the two lists is {}.", distance),
+        None => println!("The total distance between the two lists overflows."),
+    }
+    match day_2024_01::similarity_score(l1, l2) {
+        Some(score) => println!("The similarity score between the two lists is {}.", score),
+        None => println!("The similarity score between the two lists overflows."),
+    }
+
+    if let Some(path) = &options.pairs {
+        let pairs = day_2024_01::matched_pairs(l1, l2);
+        let written = File::create(path)
+            .and_then(|file| day_2024_01::write_pairs(BufWriter::new(file), &pairs));
+        if let Err(error) = written {
+            eprintln!("Cannot write the pairs to {}: {}", path, error);
+            process::exit(1);
+        }
+        println!("The {} matched pairs are written to {}.", pairs.len(), path);
+    }
+
+    if let Some(top) = options.top {
+        println!("The IDs contributing most to the similarity score:");
+        println!(
+            "  {:>12}  {:>6}  {:>6}  {:>20}",
+            "ID", "left", "right", "score"
+        );
+        for contribution in day_2024_01::similarity_contributions(l1, l2)
+            .iter()
+            .take(top)
+        {
+            let score = match contribution.score {
+                Some(score) => score.to_string(),
+                None => "overflow".to_string(),
+            };
+            println!(
+                "  {:>12}  {:>6}  {:>6}  {:>20}",
+                contribution.id, contribution.left, contribution.right, score
+            );
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use std::{
+    fmt::{self, Display, Write},
+    str::FromStr,
+};
+
+/// The directions in which the levels of a safe report may change.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Directions {
+    Increasing,
+    Decreasing,
+    /// Increasing or decreasing, but the same for the whole report.
+    Either,
+}
+
+impl Directions {
+    fn signums(&self) -> &'static [i32] {
+        match self {
+            Directions::Increasing => &[1],
+            Directions::Decreasing => &[-1],
+            Directions::Either => &[1, -1],
+        }
+    }
+}
+
+impl FromStr for Directions {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "increasing" => Ok(Directions::Increasing),
+            "decreasing" => Ok(Directions::Decreasing),
+            "either" => Ok(Directions::Either),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Directions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Directions::Increasing => write!(f, "increasing"),
+            Directions::Decreasing => write!(f, "decreasing"),
+            Directions::Either => write!(f, "either"),
+        }
+    }
+}
+
+/// The rules a report has to follow to be safe.
+///
+/// A policy file consists of `key = value` lines with the keys `min_step`, `max_step`,
+/// `directions` and `damping`, `#` starts a comment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SafetyPolicy {
+    /// A minimum of 0 allows flat steps.
+    pub min_step: i32,
+    pub max_step: i32,
+    pub directions: Directions,
+    /// The number of levels which may be removed from a report.
+    pub damping: usize,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            directions: Directions::Either,
+            damping: 0,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut policy = Self::default();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {}: expected 'key = value'", index + 1))?;
+            policy
+                .set(key.trim(), value.trim())
+                .map_err(|error| format!("line {}: {}", index + 1, error))?;
+        }
+        policy.check()?;
+        Ok(policy)
+    }
+
+    /// Sets the rule `key` to `value`.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let invalid = || format!("invalid {} '{}'", key, value);
+        match key {
+            "min_step" => self.min_step = value.parse().map_err(|_| invalid())?,
+            "max_step" => self.max_step = value.parse().map_err(|_| invalid())?,
+            "directions" => self.directions = value.parse().map_err(|_| invalid())?,
+            "damping" => self.damping = value.parse().map_err(|_| invalid())?,
+            key => return Err(format!("unknown key '{}'", key)),
+        }
+        Ok(())
+    }
+
+    pub fn check(&self) -> Result<(), String> {
+        if self.min_step < 0 || self.min_step > self.max_step {
+            return Err(format!(
+                "min_step {} does not fit max_step {}",
+                self.min_step, self.max_step
+            ));
+        }
+        Ok(())
+    }
+
+    /// The first step of the report which breaks the policy, with the index of its first
+    /// level.
+    fn first_violation(&self, report: &[i32]) -> Option<(usize, Violation)> {
+        let mut signum = match self.directions {
+            Directions::Increasing => Some(1),
+            Directions::Decreasing => Some(-1),
+            Directions::Either => None,
+        };
+        report.windows(2).enumerate().find_map(|(index, pair)| {
+            let diff = pair[1] - pair[0];
+            let violation = if diff == 0 && self.min_step > 0 {
+                Some(Violation::ZeroStep)
+            } else if diff.abs() < self.min_step {
+                Some(Violation::StepTooSmall)
+            } else if diff.abs() > self.max_step {
+                Some(Violation::StepTooLarge)
+            } else if diff != 0 && signum.is_some_and(|signum| signum != diff.signum()) {
+                match self.directions {
+                    Directions::Either => Some(Violation::DirectionChange),
+                    _ => Some(Violation::WrongDirection),
+                }
+            } else {
+                None
+            };
+            if diff != 0 && signum.is_none() {
+                signum = Some(diff.signum());
+            }
+            violation.map(|violation| (index, violation))
+        })
+    }
+
+    fn is_safe_step(&self, from: i32, to: i32, signum: i32) -> bool {
+        let diff = to - from;
+        (self.min_step..=self.max_step).contains(&diff.abs())
+            && (diff == 0 || diff.signum() == signum)
+    }
+}
+
+impl Display for SafetyPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "steps {} to {}, {} direction, up to {} removed levels",
+            self.min_step, self.max_step, self.directions, self.damping
+        )
+    }
+}
+
+/// The rule broken by a step between two levels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Violation {
+    ZeroStep,
+    StepTooSmall,
+    StepTooLarge,
+    /// The levels turn from increasing to decreasing or back.
+    DirectionChange,
+    /// The levels change against the direction required by the policy.
+    WrongDirection,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::ZeroStep => write!(f, "zero step"),
+            Violation::StepTooSmall => write!(f, "step too small"),
+            Violation::StepTooLarge => write!(f, "step too large"),
+            Violation::DirectionChange => write!(f, "direction change"),
+            Violation::WrongDirection => write!(f, "wrong direction"),
+        }
+    }
+}
+
+/// Finds the fewest levels to remove so that the report changes safely in the direction
+/// `signum`, `None` if more than `policy.damping` levels would have to be removed.
+///
+/// Every kept level is reached from one of the `damping + 1` levels before it, so this takes
+/// O(n * damping) steps.
+fn removed_levels(report: &[i32], signum: i32, policy: &SafetyPolicy) -> Option<Vec<usize>> {
+    let max_removals = policy.damping;
+    // removals[i] is the fewest removed levels before level i if level i is kept and
+    // previous[i] the kept level before it.
+    let mut removals: Vec<Option<usize>> = vec![None; report.len()];
+    let mut previous = vec![None; report.len()];
+    for i in 0..report.len() {
+        if i <= max_removals {
+            removals[i] = Some(i);
+        }
+        for j in i.saturating_sub(max_removals + 1)..i {
+            let Some(before) = removals[j] else {
+                continue;
+            };
+            let total = before + (i - j - 1);
+            if total <= max_removals
+                && removals[i].is_none_or(|removals| total < removals)
+                && policy.is_safe_step(report[j], report[i], signum)
+            {
+                removals[i] = Some(total);
+                previous[i] = Some(j);
+            }
+        }
+    }
+
+    let (last, _) = removals
+        .iter()
+        .enumerate()
+        .filter_map(|(i, removals)| removals.map(|removals| (i, removals + report.len() - 1 - i)))
+        .filter(|(_, total)| *total <= max_removals)
+        .min_by_key(|(_, total)| *total)?;
+    let mut kept = vec![false; report.len()];
+    let mut level = Some(last);
+    while let Some(i) = level {
+        kept[i] = true;
+        level = previous[i];
+    }
+    Some((0..report.len()).filter(|i| !kept[*i]).collect())
+}
+
+pub fn is_safe_report(report: &[i32], policy: &SafetyPolicy) -> bool {
+    policy
+        .directions
+        .signums()
+        .iter()
+        .any(|signum| removed_levels(report, *signum, policy).is_some())
+}
+
+pub fn parse_reports(input: &str) -> Vec<Vec<i32>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_ascii_whitespace()
+                .map(|number| number.parse::<i32>().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Why a report is safe or not.
+pub struct Diagnostic {
+    /// The line of the report in the input, starting at 1.
+    pub line: usize,
+    /// The index of the first level of the first unsafe step and the rule it breaks.
+    pub first_violation: Option<(usize, Violation)>,
+    /// The indices of the levels to remove to make the report safe, `None` if more levels
+    /// would have to be removed than the policy allows.
+    pub removed_levels: Option<Vec<usize>>,
+}
+
+impl Diagnostic {
+    fn status(&self) -> &'static str {
+        match &self.removed_levels {
+            Some(levels) if levels.is_empty() => "safe",
+            Some(_) => "damped",
+            None => "unsafe",
+        }
+    }
+}
+
+pub fn diagnose(input: &str, policy: &SafetyPolicy) -> Vec<Diagnostic> {
+    parse_reports(input)
+        .iter()
+        .enumerate()
+        .map(|(index, report)| {
+            // The status follows from the same levels as in `is_safe_report`, so that the
+            // diagnostics agree with the count.
+            let removed_levels = policy
+                .directions
+                .signums()
+                .iter()
+                .filter_map(|signum| removed_levels(report, *signum, policy))
+                .min_by_key(|levels| levels.len());
+            Diagnostic {
+                line: index + 1,
+                first_violation: policy.first_violation(report),
+                removed_levels,
+            }
+        })
+        .collect()
+}
+
+fn join(levels: &[usize]) -> String {
+    levels
+        .iter()
+        .map(|level| level.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn diagnostics_table(diagnostics: &[Diagnostic]) -> String {
+    let mut rows = vec![["Line", "Status", "Pair", "Rule", "Remove"].map(String::from)];
+    for diagnostic in diagnostics {
+        let (pair, rule) = match diagnostic.first_violation {
+            Some((index, violation)) => (format!("{}-{}", index, index + 1), violation.to_string()),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        let remove = match &diagnostic.removed_levels {
+            Some(levels) if !levels.is_empty() => join(levels),
+            _ => "-".to_string(),
+        };
+        rows.push([
+            diagnostic.line.to_string(),
+            diagnostic.status().to_string(),
+            pair,
+            rule,
+            remove,
+        ]);
+    }
+
+    let widths = (0..5)
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap())
+        .collect::<Vec<_>>();
+    let mut table = String::new();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(text, width)| format!("{:width$}", text, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(table, "{}", line.trim_end()).unwrap();
+    }
+    table
+}
+
+pub fn diagnostics_json(diagnostics: &[Diagnostic]) -> String {
+    let mut json = String::from("[\n");
+    for (index, diagnostic) in diagnostics.iter().enumerate() {
+        let first_violation = match diagnostic.first_violation {
+            Some((index, violation)) => {
+                format!("{{\"index\": {}, \"rule\": \"{}\"}}", index, violation)
+            }
+            None => "null".to_string(),
+        };
+        let removed_levels = match &diagnostic.removed_levels {
+            Some(levels) => format!("[{}]", join(levels)),
+            None => "null".to_string(),
+        };
+        writeln!(
+            json,
+            "  {{\"line\": {}, \"status\": \"{}\", \"first_violation\": {}, \"removed_levels\": {}}}{}",
+            diagnostic.line,
+            diagnostic.status(),
+            first_violation,
+            removed_levels,
+            if index + 1 < diagnostics.len() { "," } else { "" }
+        )
+        .unwrap();
+    }
+    json.push_str("]\n");
+    json
+}
+
+pub fn count_safe_reports(input: &str, policy: &SafetyPolicy) -> usize {
+    parse_reports(input)
+        .iter()
+        .filter(|report| is_safe_report(report, policy))
+        .count()
+}
+
+pub fn part_1(input: &str) -> String {
+    count_safe_reports(input, &SafetyPolicy::default()).to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let policy = SafetyPolicy {
+        damping: 1,
+        ..SafetyPolicy::default()
+    };
+    count_safe_reports(input, &policy).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn damping(damping: usize) -> SafetyPolicy {
+        SafetyPolicy {
+            damping,
+            ..SafetyPolicy::default()
+        }
+    }
+
+    #[test]
+    fn test_damping() {
+        let input = include_str!("../input/demo.txt");
+        assert_eq!(2, count_safe_reports(input, &damping(0)));
+        assert_eq!(4, count_safe_reports(input, &damping(1)));
+        assert_eq!(6, count_safe_reports(input, &damping(2)));
+    }
+
+    #[test]
+    fn test_removed_levels() {
+        assert_eq!(
+            Some(vec![2]),
+            removed_levels(&[1, 3, 2, 4, 5], 1, &damping(1))
+        );
+        assert_eq!(
+            Some(vec![0, 1]),
+            removed_levels(&[9, 9, 1, 2, 3], 1, &damping(2))
+        );
+        assert_eq!(None, removed_levels(&[9, 9, 1, 2, 3], 1, &damping(1)));
+
+        let trace = (0..5000).collect::<Vec<_>>();
+        let mut broken = trace.clone();
+        broken[1000] = 0;
+        broken[4000] = 0;
+        assert!(is_safe_report(&trace, &damping(0)));
+        assert_eq!(
+            Some(vec![1000, 4000]),
+            removed_levels(&broken, 1, &damping(2))
+        );
+    }
+
+    #[test]
+    fn test_policy() {
+        let policy = SafetyPolicy::parse(
+            "# flat steps\nmin_step = 0\nmax_step = 4\ndirections = decreasing\n",
+        )
+        .unwrap();
+        assert!(is_safe_report(&[9, 9, 5, 4], &policy));
+        assert!(!is_safe_report(&[4, 5, 9, 9], &policy));
+        assert_eq!(
+            Err("line 1: invalid directions 'up'".to_string()),
+            SafetyPolicy::parse("directions = up")
+        );
+        assert_eq!(
+            Err("min_step 4 does not fit max_step 3".to_string()),
+            SafetyPolicy::parse("min_step = 4")
+        );
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/demo.txt"),
+            &diagnostics_table(&diagnose(include_str!("../input/demo.txt"), &damping(1))),
+        );
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/demo.json"),
+            &diagnostics_json(&diagnose(include_str!("../input/demo.txt"), &damping(1))),
+        );
+
+        // An empty report is unsafe in the diagnostics as well as in the count.
+        let input = "1 2 3\n\n5\n";
+        let statuses = diagnose(input, &damping(0))
+            .iter()
+            .map(Diagnostic::status)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["safe", "unsafe", "safe"], statuses);
+        assert_eq!(2, count_safe_reports(input, &damping(0)));
+    }
+}
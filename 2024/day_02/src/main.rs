@@ -0,0 +1,114 @@
+use std::{env, fs, process};
+
+use day_2024_02::SafetyPolicy;
+
+const USAGE: &str = "Usage: day_2024_02 [--policy FILE] [--min-step STEP] [--max-step STEP] \
+[--directions increasing|decreasing|either] [--damping LEVELS] [--explain table|json]";
+
+enum Explain {
+    Table,
+    Json,
+}
+
+struct Options {
+    policy: Option<String>,
+    /// Rules of the policy given on the command line, they override the policy file.
+    rules: Vec<(&'static str, String)>,
+    explain: Option<Explain>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        policy: None,
+        rules: vec![],
+        explain: None,
+    };
+    while let Some(arg) = args.next() {
+        let key = match arg.as_str() {
+            "--policy" => {
+                options.policy = Some(args.next().ok_or("--policy expects a file.")?);
+                continue;
+            }
+            "--explain" => {
+                options.explain = match args.next().as_deref() {
+                    Some("table") => Some(Explain::Table),
+                    Some("json") => Some(Explain::Json),
+                    _ => return Err("--explain expects 'table' or 'json'.".to_string()),
+                };
+                continue;
+            }
+            "--min-step" => "min_step",
+            "--max-step" => "max_step",
+            "--directions" => "directions",
+            "--damping" => "damping",
+            arg => return Err(format!("Unknown argument '{}'.", arg)),
+        };
+        let value = args.next().ok_or(format!("{} expects a value.", arg))?;
+        options.rules.push((key, value));
+    }
+    Ok(options)
+}
+
+fn policy(options: &Options) -> Result<Option<SafetyPolicy>, String> {
+    if options.policy.is_none() && options.rules.is_empty() {
+        return Ok(None);
+    }
+    let mut policy = match &options.policy {
+        Some(path) => {
+            let content =
+                fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?;
+            SafetyPolicy::parse(&content).map_err(|error| format!("{}: {}", path, error))?
+        }
+        None => SafetyPolicy::default(),
+    };
+    for (key, value) in &options.rules {
+        policy.set(key, value)?;
+    }
+    policy.check()?;
+    Ok(Some(policy))
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+    let policy = match policy(&options) {
+        Ok(policy) => policy,
+        Err(error) => {
+            eprintln!("Invalid policy, {}", error);
+            process::exit(2);
+        }
+    };
+
+    let input = include_str!("../input/input.txt");
+
+    println!("There are {} safe reports.", day_2024_02::part_1(input));
+    println!(
+        "There are {} safe problem damped reports.",
+        day_2024_02::part_2(input)
+    );
+    if let Some(policy) = &policy {
+        println!(
+            "There are {} safe reports with {}.",
+            day_2024_02::count_safe_reports(input, policy),
+            policy
+        );
+    }
+
+    if let Some(explain) = &options.explain {
+        // Without a policy the reports are explained for the problem dampener.
+        let policy = policy.unwrap_or(SafetyPolicy {
+            damping: 1,
+            ..SafetyPolicy::default()
+        });
+        let diagnostics = day_2024_02::diagnose(input, &policy);
+        match explain {
+            Explain::Table => print!("{}", day_2024_02::diagnostics_table(&diagnostics)),
+            Explain::Json => print!("{}", day_2024_02::diagnostics_json(&diagnostics)),
+        }
+    }
+}
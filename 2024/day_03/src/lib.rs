@@ -0,0 +1,526 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display},
+    io::{self, Read, Write},
+    iter::Peekable,
+    ops::Range,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction {
+    Enable,
+    Disable,
+    Mul(i32, i32),
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Enable => write!(f, "do()"),
+            Instruction::Disable => write!(f, "don't()"),
+            Instruction::Mul(a, b) => write!(f, "mul({},{})", a, b),
+        }
+    }
+}
+
+/// Why the start of an instruction is not an instruction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Miss {
+    TooManyDigits,
+    Unexpected(u8),
+    /// The memory ends within the instruction.
+    Truncated,
+}
+
+impl Display for Miss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Miss::TooManyDigits => write!(f, "more than {} digits", MAX_DIGITS),
+            Miss::Unexpected(byte) => write!(f, "unexpected '{}'", byte.escape_ascii()),
+            Miss::Truncated => write!(f, "truncated"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    Instruction(Instruction),
+    /// A name and an opening parenthesis which do not start an instruction, with the bytes
+    /// up to where the instruction breaks.
+    NearMiss(Miss, Vec<u8>),
+}
+
+/// How an instruction is written: its name followed by its arguments in parentheses,
+/// separated by commas.
+struct Syntax {
+    name: &'static str,
+    arguments: usize,
+    build: fn(&[i32]) -> Instruction,
+}
+
+// A new instruction needs an entry here and a case in `Interpreter::execute`.
+const SYNTAX: &[Syntax] = &[
+    Syntax {
+        name: "mul",
+        arguments: 2,
+        build: |arguments| Instruction::Mul(arguments[0], arguments[1]),
+    },
+    Syntax {
+        name: "do",
+        arguments: 0,
+        build: |_| Instruction::Enable,
+    },
+    Syntax {
+        name: "don't",
+        arguments: 0,
+        build: |_| Instruction::Disable,
+    },
+];
+
+/// Arguments have one to three digits.
+const MAX_DIGITS: usize = 3;
+
+/// The length of the longest instruction.
+fn max_instruction_len() -> usize {
+    SYNTAX
+        .iter()
+        .map(|syntax| {
+            syntax.name.len()
+                + 2
+                + syntax.arguments * MAX_DIGITS
+                + syntax.arguments.saturating_sub(1)
+        })
+        .max()
+        .unwrap()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TokenKind {
+    Name(&'static str),
+    Number,
+    OpenParen,
+    Comma,
+    CloseParen,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// The bytes of the token in the input.
+    pub span: Range<usize>,
+}
+
+/// Splits the memory into tokens, bytes which cannot start a token are skipped.
+pub struct Lexer<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Self { input, position: 0 }
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.position < self.input.len() {
+            let start = self.position;
+            let rest = &self.input[start..];
+            let name = SYNTAX
+                .iter()
+                .map(|syntax| syntax.name)
+                .filter(|name| rest.starts_with(name.as_bytes()))
+                .max_by_key(|name| name.len());
+            let kind = if let Some(name) = name {
+                self.position += name.len();
+                TokenKind::Name(name)
+            } else if rest[0].is_ascii_digit() {
+                self.position += rest.iter().take_while(|byte| byte.is_ascii_digit()).count();
+                TokenKind::Number
+            } else {
+                self.position += 1;
+                match rest[0] {
+                    b'(' => TokenKind::OpenParen,
+                    b',' => TokenKind::Comma,
+                    b')' => TokenKind::CloseParen,
+                    _ => continue,
+                }
+            };
+            return Some(Token {
+                kind,
+                span: start..self.position,
+            });
+        }
+        None
+    }
+}
+
+/// Finds the instructions in the tokens of the memory. The tokens of an instruction have to
+/// follow each other without any other bytes in between.
+pub struct Parser<'a> {
+    input: &'a [u8],
+    tokens: Peekable<Lexer<'a>>,
+    /// The end of the last token taken from the lexer.
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            tokens: Lexer::new(input).peekable(),
+            end: 0,
+        }
+    }
+
+    fn next_token_if(&mut self, condition: impl FnOnce(&Token) -> bool) -> Option<Token> {
+        let token = self.tokens.next_if(condition)?;
+        self.end = token.span.end;
+        Some(token)
+    }
+
+    fn next_adjacent(&mut self, end: usize, kind: TokenKind) -> Option<Token> {
+        self.next_token_if(|token| token.span.start == end && token.kind == kind)
+    }
+
+    /// Finds the next instruction or near miss which starts before `limit`, later tokens
+    /// are left alone.
+    fn next_before(&mut self, limit: usize) -> Option<(Range<usize>, Event)> {
+        while let Some(token) = self.next_token_if(|token| token.span.start < limit) {
+            if let Some(event) = self.parse_call(&token) {
+                return Some(event);
+            }
+        }
+        None
+    }
+
+    fn near_miss(&self, start: usize, end: usize, miss: Miss) -> (Range<usize>, Event) {
+        let span = start..end.min(self.input.len());
+        let text = self.input[span.clone()].to_vec();
+        (span, Event::NearMiss(miss, text))
+    }
+
+    /// The near miss of a call which continues with an unexpected byte at `end`.
+    fn unexpected(&self, start: usize, end: usize) -> (Range<usize>, Event) {
+        match self.input.get(end) {
+            Some(byte) => self.near_miss(start, end + 1, Miss::Unexpected(*byte)),
+            None => self.near_miss(start, end, Miss::Truncated),
+        }
+    }
+
+    fn parse_call(&mut self, name: &Token) -> Option<(Range<usize>, Event)> {
+        let TokenKind::Name(name_text) = name.kind else {
+            return None;
+        };
+        let syntax = SYNTAX.iter().find(|syntax| syntax.name == name_text)?;
+        let start = name.span.start;
+
+        // Only names followed by a parenthesis are taken as near misses.
+        let mut end = self
+            .next_adjacent(name.span.end, TokenKind::OpenParen)?
+            .span
+            .end;
+        let mut arguments = vec![];
+        for index in 0..syntax.arguments {
+            if index > 0 {
+                let Some(comma) = self.next_adjacent(end, TokenKind::Comma) else {
+                    return Some(self.unexpected(start, end));
+                };
+                end = comma.span.end;
+            }
+            let Some(number) = self.next_adjacent(end, TokenKind::Number) else {
+                return Some(self.unexpected(start, end));
+            };
+            if number.span.len() > MAX_DIGITS {
+                // The near miss ends at the first extra digit. The rest of the number may not
+                // have been read yet when the memory is scanned in chunks.
+                let end = number.span.start + MAX_DIGITS + 1;
+                return Some(self.near_miss(start, end, Miss::TooManyDigits));
+            }
+            let digits = &self.input[number.span.clone()];
+            arguments.push(
+                digits
+                    .iter()
+                    .fold(0, |value, digit| value * 10 + (digit - b'0') as i32),
+            );
+            end = number.span.end;
+        }
+        let Some(close) = self.next_adjacent(end, TokenKind::CloseParen) else {
+            return Some(self.unexpected(start, end));
+        };
+        Some((
+            start..close.span.end,
+            Event::Instruction((syntax.build)(&arguments)),
+        ))
+    }
+}
+
+impl Iterator for Parser<'_> {
+    type Item = (Range<usize>, Event);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_before(usize::MAX)
+    }
+}
+
+/// The instructions in the memory without the near misses.
+pub fn instructions(input: &[u8]) -> impl Iterator<Item = (Range<usize>, Instruction)> + '_ {
+    Parser::new(input).filter_map(|(span, event)| match event {
+        Event::Instruction(instruction) => Some((span, instruction)),
+        Event::NearMiss(..) => None,
+    })
+}
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Finds the instructions and near misses in memory which is read in chunks, the spans are
+/// offsets in the whole stream.
+///
+/// Only the end of the last chunk, which may hold the start of an instruction, is kept while
+/// the next chunk is read.
+pub struct Scanner<R> {
+    reader: R,
+    chunk_size: usize,
+    buffer: Vec<u8>,
+    /// The offset of the buffer in the stream.
+    offset: usize,
+    events: VecDeque<(Range<usize>, Event)>,
+    done: bool,
+}
+
+impl<R: Read> Scanner<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_chunk_size(reader, CHUNK_SIZE)
+    }
+
+    pub fn with_chunk_size(reader: R, chunk_size: usize) -> Self {
+        Self {
+            reader,
+            chunk_size,
+            buffer: vec![],
+            offset: 0,
+            events: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    fn read_chunk(&mut self) -> io::Result<usize> {
+        let length = self.buffer.len();
+        self.buffer.resize(length + self.chunk_size, 0);
+        let read = loop {
+            match self.reader.read(&mut self.buffer[length..]) {
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                read => break read,
+            }
+        };
+        self.buffer.truncate(length + *read.as_ref().unwrap_or(&0));
+        read
+    }
+
+    fn scan_chunk(&mut self) -> io::Result<()> {
+        if self.read_chunk()? == 0 {
+            self.done = true;
+        }
+
+        // Instructions starting before the limit are complete, the rest is scanned again
+        // with the next chunk.
+        let limit = if self.done {
+            self.buffer.len()
+        } else {
+            self.buffer.len().saturating_sub(max_instruction_len())
+        };
+        let mut parser = Parser::new(&self.buffer);
+        while let Some((span, event)) = parser.next_before(limit) {
+            self.events
+                .push_back((span.start + self.offset..span.end + self.offset, event));
+        }
+        let scanned = parser.end.max(limit);
+        self.buffer.drain(..scanned);
+        self.offset += scanned;
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for Scanner<R> {
+    type Item = io::Result<(Range<usize>, Event)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some(Ok(event));
+            }
+            if self.done {
+                return None;
+            }
+            if let Err(error) = self.scan_chunk() {
+                self.done = true;
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+pub struct Interpreter {
+    pub enabled: bool,
+    pub sum: i64,
+    /// Whether `do()` and `don't()` switch `mul` on and off.
+    conditionals: bool,
+}
+
+impl Interpreter {
+    pub fn new(conditionals: bool) -> Self {
+        Self {
+            enabled: true,
+            sum: 0,
+            conditionals,
+        }
+    }
+
+    pub fn execute(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Enable => self.enabled = true,
+            Instruction::Disable => self.enabled = false,
+            Instruction::Mul(a, b) => {
+                if self.enabled || !self.conditionals {
+                    self.sum += a as i64 * b as i64;
+                }
+            }
+        }
+    }
+}
+
+/// Writes every instruction and near miss with its offset. Instructions are followed by
+/// whether `mul` is enabled after them and the running sum of part 2.
+pub fn write_trace(reader: impl Read, mut writer: impl Write) -> io::Result<()> {
+    let mut interpreter = Interpreter::new(true);
+    writeln!(
+        writer,
+        "{:>10}  {:<16}  {:<7}  Sum",
+        "Offset", "Instruction", "Enabled"
+    )?;
+    for event in Scanner::new(reader) {
+        let (span, event) = event?;
+        match event {
+            Event::Instruction(instruction) => {
+                interpreter.execute(instruction);
+                writeln!(
+                    writer,
+                    "{:>10}  {:<16}  {:<7}  {}",
+                    span.start,
+                    instruction.to_string(),
+                    if interpreter.enabled { "yes" } else { "no" },
+                    interpreter.sum
+                )?;
+            }
+            Event::NearMiss(miss, text) => writeln!(
+                writer,
+                "{:>10}  {:<16}  near miss, {}",
+                span.start,
+                text.escape_ascii().to_string(),
+                miss
+            )?,
+        }
+    }
+    writer.flush()
+}
+
+fn run(input: &str, conditionals: bool) -> i64 {
+    let mut interpreter = Interpreter::new(conditionals);
+    for (_, instruction) in instructions(input.as_bytes()) {
+        interpreter.execute(instruction);
+    }
+    interpreter.sum
+}
+
+pub fn part_1(input: &str) -> String {
+    run(input, false).to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    run(input, true).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let kinds = Lexer::new(b"xmul(2,4)%don't()")
+            .map(|token| (token.kind, token.span))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (TokenKind::Name("mul"), 1..4),
+                (TokenKind::OpenParen, 4..5),
+                (TokenKind::Number, 5..6),
+                (TokenKind::Comma, 6..7),
+                (TokenKind::Number, 7..8),
+                (TokenKind::CloseParen, 8..9),
+                (TokenKind::Name("don't"), 10..15),
+                (TokenKind::OpenParen, 15..16),
+                (TokenKind::CloseParen, 16..17),
+            ],
+            kinds
+        );
+    }
+
+    #[test]
+    fn test_instructions() {
+        let events = Parser::new(b"mul(mul(1234,5)mul(2,3)do(1)don't()mul(4*").collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (
+                    0..5,
+                    Event::NearMiss(Miss::Unexpected(b'm'), b"mul(m".to_vec())
+                ),
+                (
+                    4..12,
+                    Event::NearMiss(Miss::TooManyDigits, b"mul(1234".to_vec())
+                ),
+                (15..23, Event::Instruction(Instruction::Mul(2, 3))),
+                (
+                    23..27,
+                    Event::NearMiss(Miss::Unexpected(b'1'), b"do(1".to_vec())
+                ),
+                (28..35, Event::Instruction(Instruction::Disable)),
+                (
+                    35..41,
+                    Event::NearMiss(Miss::Unexpected(b'*'), b"mul(4*".to_vec())
+                ),
+            ],
+            events
+        );
+    }
+
+    #[test]
+    fn test_chunks() {
+        let mut memory = include_bytes!("../input/demo2.txt").to_vec();
+        memory.extend_from_slice(b"\xff\xfemul(12345,6)mul(123,4)do\x80()don't()mul(1,1)");
+        // A number longer than any chunk.
+        memory.extend_from_slice(b"mul(7,");
+        memory.extend_from_slice(&[b'9'; 40]);
+        memory.extend_from_slice(b")mul(2,3)");
+        let expected = Parser::new(&memory).collect::<Vec<_>>();
+        assert_eq!(13, expected.len());
+        for chunk_size in 1..=20 {
+            let instructions = Scanner::with_chunk_size(memory.as_slice(), chunk_size)
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap();
+            assert_eq!(expected, instructions, "chunk size {}", chunk_size);
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut trace = vec![];
+        write_trace(include_bytes!("../input/demo2.txt").as_slice(), &mut trace).unwrap();
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/demo2.trace"),
+            &String::from_utf8(trace).unwrap(),
+        );
+    }
+}
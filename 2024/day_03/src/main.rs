@@ -0,0 +1,96 @@
+use std::{
+    env,
+    fs::File,
+    io::{self, BufWriter},
+    process,
+};
+
+use day_2024_03::{Event, Interpreter, Scanner};
+
+const USAGE: &str = "Usage: day_2024_03 [--input FILE] [--trace]";
+
+struct Options {
+    input: Option<String>,
+    trace: bool,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        input: None,
+        trace: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--trace" => options.trace = true,
+            "--input" => options.input = Some(args.next().ok_or("--input expects a file.")?),
+            arg => return Err(format!("Unknown argument '{}'.", arg)),
+        }
+    }
+    Ok(options)
+}
+
+fn open(path: &str) -> File {
+    File::open(path).unwrap_or_else(|error| {
+        eprintln!("Cannot read {}: {}", path, error);
+        process::exit(1);
+    })
+}
+
+fn trace(options: &Options) {
+    let writer = BufWriter::new(io::stdout().lock());
+    let traced = match &options.input {
+        Some(path) => day_2024_03::write_trace(open(path), writer),
+        None => day_2024_03::write_trace(include_bytes!("../input/input.txt").as_slice(), writer),
+    };
+    if let Err(error) = traced {
+        eprintln!("Cannot write the trace: {}", error);
+        process::exit(1);
+    }
+}
+
+/// Scans a memory dump of any size once for both results.
+fn scan(path: &str) {
+    let file = open(path);
+    let mut unconditional = Interpreter::new(false);
+    let mut conditional = Interpreter::new(true);
+    for instruction in Scanner::new(file) {
+        match instruction {
+            Ok((_, Event::Instruction(instruction))) => {
+                unconditional.execute(instruction);
+                conditional.execute(instruction);
+            }
+            Ok((_, Event::NearMiss(..))) => {}
+            Err(error) => {
+                eprintln!("Cannot read {}: {}", path, error);
+                process::exit(1);
+            }
+        }
+    }
+
+    println!("The result is {}.", unconditional.sum);
+    println!("The second result is {}.", conditional.sum);
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if options.trace {
+        trace(&options);
+        return;
+    }
+    if let Some(path) = &options.input {
+        scan(path);
+        return;
+    }
+
+    let input = include_str!("../input/input.txt");
+
+    println!("The result is {}.", day_2024_03::part_1(input));
+    println!("The second result is {}.", day_2024_03::part_2(input));
+}
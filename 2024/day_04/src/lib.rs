@@ -0,0 +1,440 @@
+use std::str::FromStr;
+
+/// The letters of the field in rows, the field only holds ASCII letters.
+struct Field {
+    letters: Vec<u8>,
+    width: i32,
+    height: i32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl Direction {
+    fn offsets(&self) -> (i32, i32) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::East => (0, 1),
+            Direction::South => (1, 0),
+            Direction::West => (0, -1),
+            Direction::NorthEast => (-1, 1),
+            Direction::NorthWest => (-1, -1),
+            Direction::SouthEast => (1, 1),
+            Direction::SouthWest => (1, -1),
+        }
+    }
+
+    /// The ANSI colour code of matches in this direction.
+    fn color(&self) -> u8 {
+        match self {
+            Direction::North => 31,
+            Direction::East => 32,
+            Direction::South => 33,
+            Direction::West => 34,
+            Direction::NorthEast => 35,
+            Direction::NorthWest => 36,
+            Direction::SouthEast => 91,
+            Direction::SouthWest => 92,
+        }
+    }
+}
+
+struct DirectionIterator {
+    count: usize,
+}
+
+impl DirectionIterator {
+    fn new() -> Self {
+        Self { count: 0 }
+    }
+}
+
+impl Iterator for DirectionIterator {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = match self.count {
+            0 => Some(Direction::North),
+            1 => Some(Direction::East),
+            2 => Some(Direction::South),
+            3 => Some(Direction::West),
+            4 => Some(Direction::NorthEast),
+            5 => Some(Direction::NorthWest),
+            6 => Some(Direction::SouthEast),
+            7 => Some(Direction::SouthWest),
+            _ => None,
+        };
+        self.count += 1;
+        n
+    }
+}
+
+impl Field {
+    fn new(data: &str) -> Self {
+        let lines = data
+            .lines()
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>();
+        let width = lines[0].len();
+        assert!(
+            lines.iter().all(|line| line.len() == width),
+            "The rows of the field differ in length."
+        );
+        Field {
+            letters: lines.iter().flat_map(|line| line.bytes()).collect(),
+            width: width as i32,
+            height: lines.len() as i32,
+        }
+    }
+
+    fn get(&self, row: i32, column: i32) -> Option<u8> {
+        if (0..self.width).contains(&column) && (0..self.height).contains(&row) {
+            return Some(self.letters[(row * self.width + column) as usize]);
+        }
+
+        None
+    }
+
+    fn positions(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        (0..self.height).flat_map(move |row| (0..self.width).map(move |column| (row, column)))
+    }
+}
+
+/// The words to search as a prefix tree, so that all of them are matched in one walk.
+struct Trie {
+    nodes: Vec<TrieNode>,
+}
+
+#[derive(Default)]
+struct TrieNode {
+    children: Vec<(u8, usize)>,
+    /// The indices of the words ending at this node.
+    words: Vec<usize>,
+}
+
+impl Trie {
+    fn new(words: &[&str]) -> Self {
+        let mut nodes = vec![TrieNode::default()];
+        for (index, word) in words.iter().enumerate() {
+            let mut node = 0;
+            for c in word.bytes() {
+                node = match nodes[node].children.iter().find(|(child, _)| *child == c) {
+                    Some((_, child)) => *child,
+                    None => {
+                        nodes.push(TrieNode::default());
+                        let child = nodes.len() - 1;
+                        nodes[node].children.push((c, child));
+                        child
+                    }
+                };
+            }
+            nodes[node].words.push(index);
+        }
+        Trie { nodes }
+    }
+
+    fn child(&self, node: usize, c: u8) -> Option<usize> {
+        self.nodes[node]
+            .children
+            .iter()
+            .find(|(child, _)| *child == c)
+            .map(|(_, child)| *child)
+    }
+}
+
+/// A word found in the field, starting at `row` and `column`.
+#[derive(Debug, PartialEq)]
+pub struct Match {
+    /// The index of the word in the searched words.
+    pub word: usize,
+    pub row: i32,
+    pub column: i32,
+    pub direction: Direction,
+}
+
+/// Finds all words in all directions. Each letter and direction is only visited as long as
+/// the letters on the way start one of the words.
+pub fn search(input: &str, words: &[&str]) -> Vec<Match> {
+    let field = Field::new(input);
+    let trie = Trie::new(words);
+
+    let mut matches = vec![];
+    for (row, column) in field.positions() {
+        for direction in DirectionIterator::new() {
+            let offsets = direction.offsets();
+            let mut node = 0;
+            let mut step = 0;
+            while let Some(next) = field
+                .get(row + step * offsets.0, column + step * offsets.1)
+                .and_then(|c| trie.child(node, c))
+            {
+                node = next;
+                step += 1;
+                // Single letters read the same in all directions, they are found once.
+                if step == 1 && direction != Direction::North {
+                    continue;
+                }
+                matches.extend(trie.nodes[node].words.iter().map(|word| Match {
+                    word: *word,
+                    row,
+                    column,
+                    direction,
+                }));
+            }
+        }
+    }
+    matches
+}
+
+/// For every cell of the field the direction of the first match it belongs to.
+fn match_directions(input: &str, words: &[&str]) -> (Field, Vec<Option<Direction>>) {
+    let field = Field::new(input);
+    let mut directions = vec![None; field.letters.len()];
+    for m in search(input, words) {
+        let offsets = m.direction.offsets();
+        for step in 0..words[m.word].len() as i32 {
+            let row = m.row + step * offsets.0;
+            let column = m.column + step * offsets.1;
+            directions[(row * field.width + column) as usize].get_or_insert(m.direction);
+        }
+    }
+    (field, directions)
+}
+
+fn render(input: &str, words: &[&str], color: bool) -> String {
+    let (field, directions) = match_directions(input, words);
+    let mut output = String::new();
+    for (row, column) in field.positions() {
+        let letter = field.get(row, column).unwrap() as char;
+        match directions[(row * field.width + column) as usize] {
+            Some(direction) if color => {
+                output.push_str(&format!("\x1b[{}m{}\x1b[0m", direction.color(), letter))
+            }
+            Some(_) => output.push(letter),
+            None => output.push('.'),
+        }
+        if column == field.width - 1 {
+            output.push('\n');
+        }
+    }
+    output
+}
+
+/// Renders the field with the letters of all matches of the words, the other letters are
+/// replaced by `.`.
+pub fn render_matches(input: &str, words: &[&str]) -> String {
+    render(input, words, false)
+}
+
+/// Renders the field like `render_matches` with ANSI colours, every direction of the matches
+/// has its own colour.
+pub fn render_colored_matches(input: &str, words: &[&str]) -> String {
+    render(input, words, true)
+}
+
+/// A small pattern of letters, written row by row with `/` between the rows. `.` matches
+/// any letter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    /// The letters with their offsets, wildcards are left out.
+    letters: Vec<(i32, i32, u8)>,
+    /// The size of the pattern including the wildcards on its edges, which have to be inside
+    /// the field too.
+    height: i32,
+    width: i32,
+}
+
+impl FromStr for Template {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let height = input.split('/').count() as i32;
+        let width = input.split('/').map(str::len).max().unwrap() as i32;
+        let letters = input
+            .split('/')
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.bytes()
+                    .enumerate()
+                    .filter(|(_, letter)| *letter != b'.')
+                    .map(move |(column, letter)| (row as i32, column as i32, letter))
+            })
+            .collect::<Vec<_>>();
+        if letters.is_empty() {
+            return Err(());
+        }
+        Ok(Template {
+            letters,
+            height,
+            width,
+        }
+        .normalized())
+    }
+}
+
+impl Template {
+    /// Sorts the letters, so that equal templates compare equal.
+    fn normalized(mut self) -> Self {
+        self.letters.sort();
+        self
+    }
+
+    /// The template turned clockwise by a quarter.
+    fn rotated(&self) -> Self {
+        Template {
+            letters: self
+                .letters
+                .iter()
+                .map(|(row, column, letter)| (*column, self.height - 1 - row, *letter))
+                .collect(),
+            height: self.width,
+            width: self.height,
+        }
+        .normalized()
+    }
+
+    /// The template mirrored left to right.
+    fn reflected(&self) -> Self {
+        Template {
+            letters: self
+                .letters
+                .iter()
+                .map(|(row, column, letter)| (*row, self.width - 1 - column, *letter))
+                .collect(),
+            height: self.height,
+            width: self.width,
+        }
+        .normalized()
+    }
+
+    /// The distinct orientations of the template. Symmetric templates have fewer than 4, or
+    /// 8 with reflections.
+    pub fn variants(&self, reflections: bool) -> Vec<Template> {
+        let mut variants: Vec<Template> = vec![];
+        let mut variant = self.clone();
+        for _ in 0..4 {
+            let reflected = variant.reflected();
+            for candidate in [Some(variant.clone()), reflections.then_some(reflected)]
+                .into_iter()
+                .flatten()
+            {
+                if !variants.contains(&candidate) {
+                    variants.push(candidate);
+                }
+            }
+            variant = variant.rotated();
+        }
+        variants
+    }
+
+    fn matches(&self, field: &Field, row: i32, column: i32) -> bool {
+        row + self.height <= field.height
+            && column + self.width <= field.width
+            && self
+                .letters
+                .iter()
+                .all(|(r, c, letter)| field.get(row + r, column + c) == Some(*letter))
+    }
+}
+
+/// Counts the placements of the template in all rotations and, if asked for, reflections.
+pub fn count_template(input: &str, template: &Template, reflections: bool) -> usize {
+    let field = Field::new(input);
+    let variants = template.variants(reflections);
+    field
+        .positions()
+        .map(|(row, column)| {
+            variants
+                .iter()
+                .filter(|variant| variant.matches(&field, row, column))
+                .count()
+        })
+        .sum()
+}
+
+pub fn part_1(input: &str) -> String {
+    search(input, &["XMAS"]).len().to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let x_mas = "M.S/.A./M.S".parse::<Template>().unwrap();
+    count_template(input, &x_mas, false).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search() {
+        let input = include_str!("../input/demo.txt");
+        let matches = search(input, &["XMAS", "MAS", "XMAS", "Q"]);
+        let count = |word| matches.iter().filter(|m| m.word == word).count();
+        assert_eq!(18, count(0));
+        assert_eq!(18, count(2));
+        assert_eq!(0, count(3));
+        assert!(matches.contains(&Match {
+            word: 0,
+            row: 0,
+            column: 5,
+            direction: Direction::East
+        }));
+        assert_eq!(
+            2,
+            search(input, &["X"]).iter().filter(|m| m.row == 0).count()
+        );
+    }
+
+    #[test]
+    fn test_template() {
+        let input = include_str!("../input/demo.txt");
+        let x_mas = "M.S/.A./M.S".parse::<Template>().unwrap();
+        assert_eq!(4, x_mas.variants(true).len());
+        assert_eq!(9, count_template(input, &x_mas, false));
+
+        let square = "A.A/.A./A.A".parse::<Template>().unwrap();
+        assert_eq!(1, square.variants(true).len());
+
+        // An L shape has 8 orientations, the word MS only 4.
+        assert_eq!(8, "MS/M.".parse::<Template>().unwrap().variants(true).len());
+        let word = "MS".parse::<Template>().unwrap();
+        assert_eq!(4, word.variants(true).len());
+        assert_eq!(
+            count_template(input, &word, true),
+            search(input, &["MS"])
+                .iter()
+                .filter(|m| !matches!(
+                    m.direction,
+                    Direction::NorthEast
+                        | Direction::NorthWest
+                        | Direction::SouthEast
+                        | Direction::SouthWest
+                ))
+                .count()
+        );
+        assert_eq!(Err(()), "../...".parse::<Template>());
+
+        // Wildcards on the edge keep their place, each orientation has to fit into the field.
+        let edge = ".A".parse::<Template>().unwrap();
+        assert_ne!("A".parse::<Template>().unwrap(), edge);
+        assert_eq!(4, edge.variants(false).len());
+        assert_eq!(4, count_template("AB\nBA\n", &edge, false));
+    }
+
+    #[test]
+    fn test_render_matches() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/demo.txt"),
+            &render_matches(include_str!("../input/demo.txt"), &["XMAS"]),
+        );
+    }
+}
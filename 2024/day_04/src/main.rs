@@ -0,0 +1,117 @@
+use std::{env, fs, process};
+
+use day_2024_04::Template;
+
+const USAGE: &str = "Usage: day_2024_04 [--input FILE] [--words WORD,WORD,...] [--template PATTERN [--reflections]] [--render | --color]";
+
+struct Options {
+    input: Option<String>,
+    words: Vec<String>,
+    template: Option<Template>,
+    reflections: bool,
+    render: Option<Render>,
+}
+
+enum Render {
+    Plain,
+    Color,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        input: None,
+        words: vec![],
+        template: None,
+        reflections: false,
+        render: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => options.input = Some(args.next().ok_or("--input expects a file.")?),
+            "--words" => {
+                let words = args.next().ok_or("--words expects a list of words.")?;
+                options.words = words
+                    .split(',')
+                    .filter(|word| !word.is_empty())
+                    .map(String::from)
+                    .collect();
+            }
+            "--template" => {
+                let template = args.next().ok_or("--template expects a pattern.")?;
+                options.template = Some(
+                    template
+                        .parse()
+                        .map_err(|_| format!("Invalid template '{}'.", template))?,
+                );
+            }
+            "--reflections" => options.reflections = true,
+            "--render" => options.render = Some(Render::Plain),
+            "--color" => options.render = Some(Render::Color),
+            arg => return Err(format!("Unknown argument '{}'.", arg)),
+        }
+    }
+    Ok(options)
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let input = match &options.input {
+        Some(path) => fs::read_to_string(path).unwrap_or_else(|error| {
+            eprintln!("Cannot read {}: {}", path, error);
+            process::exit(1);
+        }),
+        None => include_str!("../input/input.txt").to_string(),
+    };
+    let input = input.as_str();
+
+    if let Some(template) = &options.template {
+        println!(
+            "The template is placed {} times in the field.",
+            day_2024_04::count_template(input, template, options.reflections)
+        );
+        return;
+    }
+    let mut words = options.words.iter().map(String::as_str).collect::<Vec<_>>();
+    if let Some(render) = &options.render {
+        // Without a list of words the matches of part 1 are shown.
+        if words.is_empty() {
+            words.push("XMAS");
+        }
+        match render {
+            Render::Plain => print!("{}", day_2024_04::render_matches(input, &words)),
+            Render::Color => print!("{}", day_2024_04::render_colored_matches(input, &words)),
+        }
+        return;
+    }
+    if !words.is_empty() {
+        let matches = day_2024_04::search(input, &words);
+        for (index, word) in words.iter().enumerate() {
+            let positions = matches
+                .iter()
+                .filter(|m| m.word == index)
+                .map(|m| format!("({},{}) {:?}", m.row, m.column, m.direction))
+                .collect::<Vec<_>>();
+            println!("There are {} {} in the field.", positions.len(), word);
+            for position in positions {
+                println!("  {}", position);
+            }
+        }
+        return;
+    }
+
+    println!(
+        "There are {} XMAS in the filed.",
+        day_2024_04::part_1(input)
+    );
+    println!(
+        "There are {} X-MAS in the filed.",
+        day_2024_04::part_2(input)
+    );
+}
@@ -0,0 +1,530 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::{self, Display, Write},
+};
+
+struct Condition {
+    before: u32,
+    after: u32,
+}
+
+type Conditions = Vec<Condition>;
+
+/// Pages below this number are looked up in a bit matrix.
+const SMALL_PAGES: u32 = 128;
+
+/// The conditions indexed by their pages, so that looking up the rule of two pages does not
+/// depend on the number of conditions.
+struct Rules {
+    /// Bit `after` of row `before` is set if `before` has to come before `after`.
+    small: Vec<u128>,
+    /// The pages which have to come after a page, for rules with a page of 128 or more.
+    large: HashMap<u32, HashSet<u32>>,
+}
+
+impl Rules {
+    fn new(conditions: &Conditions) -> Self {
+        let mut rules = Rules {
+            small: vec![0; SMALL_PAGES as usize],
+            large: HashMap::new(),
+        };
+        for condition in conditions {
+            if condition.before < SMALL_PAGES && condition.after < SMALL_PAGES {
+                rules.small[condition.before as usize] |= 1 << condition.after;
+            } else {
+                rules
+                    .large
+                    .entry(condition.before)
+                    .or_default()
+                    .insert(condition.after);
+            }
+        }
+        rules
+    }
+
+    /// The pages among `pages` which a rule requires to be printed after `before`. The small
+    /// pages among them are also given as a bit mask.
+    fn afters_among(
+        &self,
+        before: u32,
+        small_pages: u128,
+        pages: &HashMap<u32, Vec<usize>>,
+    ) -> Vec<u32> {
+        let mut afters = vec![];
+        if before < SMALL_PAGES {
+            let mut bits = self.small[before as usize] & small_pages;
+            while bits != 0 {
+                afters.push(bits.trailing_zeros());
+                bits &= bits - 1;
+            }
+        }
+        if let Some(large) = self.large.get(&before) {
+            // Whichever is smaller is walked, the rules of the page or the pages.
+            if large.len() <= pages.len() {
+                afters.extend(large.iter().filter(|after| pages.contains_key(after)));
+            } else {
+                afters.extend(pages.keys().filter(|after| large.contains(after)));
+            }
+        }
+        afters
+    }
+
+    /// Whether a rule requires `before` to be printed before `after`.
+    fn requires(&self, before: u32, after: u32) -> bool {
+        if before < SMALL_PAGES && after < SMALL_PAGES {
+            self.small[before as usize] & 1 << after != 0
+        } else {
+            self.large
+                .get(&before)
+                .is_some_and(|afters| afters.contains(&after))
+        }
+    }
+}
+
+/// A rule which an update breaks, the positions start at 0.
+#[derive(Debug, PartialEq)]
+struct Violation {
+    before: u32,
+    after: u32,
+    before_position: usize,
+    after_position: usize,
+}
+
+/// Finds the first page which comes after a page it has to be printed before. The small pages
+/// printed so far are kept as a bit mask, so that each page is checked against all of them at
+/// once.
+fn first_violation(order: &[u32], rules: &Rules) -> Option<Violation> {
+    let mut printed_small = 0u128;
+    let mut printed_large = HashSet::new();
+    for (before_position, before) in order.iter().enumerate() {
+        let is_printed = |page: &u32| {
+            if *page < SMALL_PAGES {
+                printed_small & 1 << page != 0
+            } else {
+                printed_large.contains(page)
+            }
+        };
+        // The small printed pages of a small page are covered by the bit mask.
+        let printed = if *before < SMALL_PAGES {
+            printed_large.len()
+        } else {
+            before_position
+        };
+        let broken = (*before < SMALL_PAGES && rules.small[*before as usize] & printed_small != 0)
+            || rules.large.get(before).is_some_and(|afters| {
+                // Whichever is smaller is walked, the rules of the page or the printed pages.
+                if afters.len() <= printed {
+                    afters.iter().any(is_printed)
+                } else if *before < SMALL_PAGES {
+                    printed_large.iter().any(|page| afters.contains(page))
+                } else {
+                    order[..before_position]
+                        .iter()
+                        .any(|page| afters.contains(page))
+                }
+            });
+        if broken {
+            let after_position = order[..before_position]
+                .iter()
+                .position(|after| rules.requires(*before, *after))
+                .unwrap();
+            return Some(Violation {
+                before: *before,
+                after: order[after_position],
+                before_position,
+                after_position,
+            });
+        }
+        if *before < SMALL_PAGES {
+            printed_small |= 1 << before;
+        } else {
+            printed_large.insert(*before);
+        }
+    }
+    None
+}
+
+fn is_valid_order(order: &[u32], rules: &Rules) -> bool {
+    first_violation(order, rules).is_none()
+}
+
+fn sum_middle_pages(orders: &[Vec<u32>]) -> u32 {
+    orders.iter().map(|order| order[order.len() / 2]).sum()
+}
+
+/// The rules between the pages of an update form a cycle.
+#[derive(Debug, PartialEq)]
+pub struct CycleError {
+    /// The pages of the cycle, each one has to come before the next and the last before the
+    /// first.
+    pub pages: Vec<u32>,
+}
+
+impl Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pages = self
+            .pages
+            .iter()
+            .chain(self.pages.first())
+            .map(|page| page.to_string())
+            .collect::<Vec<_>>();
+        write!(f, "The rules form a cycle {}.", pages.join(" -> "))
+    }
+}
+
+/// Sorts the pages topologically by the rules among them. Where the rules do not determine
+/// the order, the pages keep their order in the update, so the result is unique.
+fn fix_order(order: &[u32], rules: &Rules) -> Result<Vec<u32>, CycleError> {
+    let mut positions: HashMap<u32, Vec<usize>> = HashMap::new();
+    let mut small_pages = 0u128;
+    for (position, page) in order.iter().enumerate() {
+        positions.entry(*page).or_default().push(position);
+        if *page < SMALL_PAGES {
+            small_pages |= 1 << page;
+        }
+    }
+    let mut successors = vec![vec![]; order.len()];
+    let mut predecessors = vec![vec![]; order.len()];
+    for (before, page) in order.iter().enumerate() {
+        for after_page in rules.afters_among(*page, small_pages, &positions) {
+            for after in &positions[&after_page] {
+                successors[before].push(*after);
+                predecessors[*after].push(before);
+            }
+        }
+    }
+    // In the order of the update, so that the same cycle is found every time.
+    for positions in successors.iter_mut().chain(predecessors.iter_mut()) {
+        positions.sort();
+    }
+
+    let mut incoming = predecessors.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut ready = (0..order.len())
+        .filter(|index| incoming[*index] == 0)
+        .collect::<BTreeSet<_>>();
+    let mut new_order = Vec::new();
+    while let Some(index) = ready.pop_first() {
+        new_order.push(order[index]);
+        for successor in &successors[index] {
+            incoming[*successor] -= 1;
+            if incoming[*successor] == 0 {
+                ready.insert(*successor);
+            }
+        }
+    }
+    if new_order.len() == order.len() {
+        return Ok(new_order);
+    }
+
+    // Every page left has a predecessor which is left, going back from one of them leads
+    // into a cycle.
+    let mut path = vec![(0..order.len()).find(|index| incoming[*index] > 0).unwrap()];
+    loop {
+        let last = *path.last().unwrap();
+        let predecessor = *predecessors[last]
+            .iter()
+            .find(|predecessor| incoming[**predecessor] > 0)
+            .unwrap();
+        if let Some(start) = path.iter().position(|index| *index == predecessor) {
+            let mut pages = path[start..]
+                .iter()
+                .map(|index| order[*index])
+                .collect::<Vec<_>>();
+            pages.reverse();
+            return Err(CycleError { pages });
+        }
+        path.push(predecessor);
+    }
+}
+
+/// Moving `page` from the position `from` to `to`, the other pages in between move up or down.
+#[derive(Debug, PartialEq)]
+struct Move {
+    page: u32,
+    from: usize,
+    to: usize,
+}
+
+/// The moves which turn the order into the fixed order, placing one page after the other.
+fn repair_moves(order: &[u32], fixed_order: &[u32]) -> Vec<Move> {
+    let mut order = order.to_vec();
+    let mut moves = vec![];
+    for (to, page) in fixed_order.iter().enumerate() {
+        let from = order.iter().position(|p| p == page).unwrap();
+        if from != to {
+            order.remove(from);
+            order.insert(to, *page);
+            moves.push(Move {
+                page: *page,
+                from,
+                to,
+            });
+        }
+    }
+    moves
+}
+
+/// Describes for every invalid update the first rule it breaks and how it is repaired.
+pub fn audit(input: &str) -> String {
+    let (conditions, pages) = parse_input(input);
+    let rules = Rules::new(&conditions);
+
+    let mut report = String::new();
+    for (index, order) in pages.iter().enumerate() {
+        let Some(violation) = first_violation(order, &rules) else {
+            continue;
+        };
+        let order_string = order
+            .iter()
+            .map(|page| page.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(report, "Update {}: {}", index + 1, order_string).unwrap();
+        writeln!(
+            report,
+            "  Rule {}|{} is broken, {} is at position {} and {} at position {}.",
+            violation.before,
+            violation.after,
+            violation.before,
+            violation.before_position,
+            violation.after,
+            violation.after_position
+        )
+        .unwrap();
+        match fix_order(order, &rules) {
+            Ok(fixed_order) => {
+                for m in repair_moves(order, &fixed_order) {
+                    writeln!(
+                        report,
+                        "  Move {} from position {} to {}.",
+                        m.page, m.from, m.to
+                    )
+                    .unwrap();
+                }
+            }
+            Err(error) => writeln!(report, "  {}", error).unwrap(),
+        }
+    }
+    report
+}
+
+/// Renders the rules as a Graphviz graph. The pages of the update with the given number,
+/// starting at 1, are highlighted and the rules it breaks are coloured red. Fails if there is
+/// no update with the number.
+pub fn dot_script(input: &str, update: Option<usize>) -> Result<String, String> {
+    let (conditions, pages) = parse_input(input);
+    let order = match update {
+        Some(update) => update
+            .checked_sub(1)
+            .and_then(|index| pages.get(index))
+            .ok_or(format!(
+                "There is no update {}, the updates are numbered 1 to {}.",
+                update,
+                pages.len()
+            ))?,
+        None => &vec![],
+    };
+    let position = |page| order.iter().position(|p| *p == page);
+
+    let mut script = String::new();
+    writeln!(script, "digraph {{").unwrap();
+    for condition in &conditions {
+        match (position(condition.before), position(condition.after)) {
+            (Some(before), Some(after)) if before > after => writeln!(
+                script,
+                "{} -> {} [color=red,penwidth=2];",
+                condition.before, condition.after
+            ),
+            _ => writeln!(script, "{} -> {};", condition.before, condition.after),
+        }
+        .unwrap();
+    }
+    let mut highlighted = order.to_vec();
+    highlighted.sort();
+    for page in highlighted {
+        writeln!(script, "{} [color=lightblue,style=filled];", page).unwrap();
+    }
+    writeln!(script, "}}").unwrap();
+    Ok(script)
+}
+
+pub fn print_dot_script(input: &str, update: Option<usize>) -> Result<(), String> {
+    print!("{}", dot_script(input, update)?);
+    Ok(())
+}
+
+fn parse_input(input: &str) -> (Conditions, Vec<Vec<u32>>) {
+    let (upper_part, lower_part) = input.split_once("\n\n").unwrap();
+    let conditions = upper_part
+        .lines()
+        .map(|line| {
+            let (a, b) = line.split_once('|').unwrap();
+            let before = a.parse().unwrap();
+            let after = b.parse().unwrap();
+            Condition { before, after }
+        })
+        .collect::<Vec<_>>();
+    let pages = lower_part
+        .lines()
+        .map(|line| {
+            line.split(',')
+                .map(|number| number.parse::<u32>().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    (conditions, pages)
+}
+
+pub fn part_1(input: &str) -> String {
+    let (conditions, pages) = parse_input(input);
+    let rules = Rules::new(&conditions);
+
+    let valid_orders = pages
+        .into_iter()
+        .filter(|order| is_valid_order(order, &rules))
+        .collect::<Vec<_>>();
+
+    let sum = sum_middle_pages(&valid_orders);
+    sum.to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let (conditions, pages) = parse_input(input);
+    let rules = Rules::new(&conditions);
+
+    let fixed_orders = pages
+        .iter()
+        .filter(|order| !is_valid_order(order, &rules))
+        .map(|order| fix_order(order, &rules).unwrap_or_else(|error| panic!("{}", error)))
+        .collect::<Vec<_>>();
+    let sum = sum_middle_pages(&fixed_orders);
+    sum.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fix_order() {
+        let (conditions, _) = parse_input(include_str!("../input/demo.txt"));
+        let rules = Rules::new(&conditions);
+        assert_eq!(
+            Ok(vec![97, 75, 47, 61, 53]),
+            fix_order(&[75, 97, 47, 61, 53], &rules)
+        );
+        assert_eq!(Ok(vec![61, 29, 13]), fix_order(&[61, 13, 29], &rules));
+        // The rules say nothing about 1 and 2, they keep their order.
+        assert_eq!(Ok(vec![2, 1, 97, 13]), fix_order(&[2, 13, 1, 97], &rules));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let conditions = vec![
+            Condition {
+                before: 1,
+                after: 2,
+            },
+            Condition {
+                before: 2,
+                after: 3,
+            },
+            Condition {
+                before: 3,
+                after: 1,
+            },
+            Condition {
+                before: 4,
+                after: 1,
+            },
+        ];
+        let error = fix_order(&[4, 3, 2, 1], &Rules::new(&conditions)).unwrap_err();
+        assert_eq!(vec![1, 2, 3], error.pages);
+        assert_eq!(
+            "The rules form a cycle 1 -> 2 -> 3 -> 1.",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_rules() {
+        let conditions = (0..100_000)
+            .map(|page| Condition {
+                before: page,
+                after: page + 1,
+            })
+            .collect::<Vec<_>>();
+        let rules = Rules::new(&conditions);
+        assert!(rules.requires(5, 6));
+        assert!(rules.requires(127, 128));
+        assert!(rules.requires(99_999, 100_000));
+        assert!(!rules.requires(6, 5));
+        assert!(is_valid_order(&[3, 4, 70_000, 70_001], &rules));
+        assert!(!is_valid_order(&[70_001, 3, 70_000], &rules));
+        // Long updates are checked in one pass.
+        let order = (0..=100_000).collect::<Vec<_>>();
+        assert!(is_valid_order(&order, &rules));
+        assert_eq!(
+            Some(Violation {
+                before: 127,
+                after: 128,
+                before_position: 2,
+                after_position: 1,
+            }),
+            first_violation(&[200, 128, 127, 126], &rules)
+        );
+        assert_eq!(
+            Ok(vec![3, 70_000, 70_001]),
+            fix_order(&[70_001, 3, 70_000], &rules)
+        );
+        // Long updates are fixed without comparing every pair of pages.
+        let mut reversed = order.clone();
+        reversed.reverse();
+        assert_eq!(Ok(order), fix_order(&reversed, &rules));
+    }
+
+    #[test]
+    fn test_many_rules_of_a_page() {
+        // Page 200000 has to come before 100000 other pages.
+        let conditions = (0..100_000)
+            .map(|page| Condition {
+                before: 200_000,
+                after: page,
+            })
+            .collect::<Vec<_>>();
+        let rules = Rules::new(&conditions);
+        let order = (0..10_000).chain([200_000]).collect::<Vec<_>>();
+        assert_eq!(
+            Some(Violation {
+                before: 200_000,
+                after: 0,
+                before_position: 10_000,
+                after_position: 0,
+            }),
+            first_violation(&order, &rules)
+        );
+        let fixed = fix_order(&order, &rules).unwrap();
+        assert_eq!(200_000, fixed[0]);
+    }
+
+    #[test]
+    fn test_audit() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/demo.txt"),
+            &audit(include_str!("../input/demo.txt")),
+        );
+    }
+
+    #[test]
+    fn test_dot_script() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/demo.dot"),
+            &dot_script(include_str!("../input/demo.txt"), Some(6)).unwrap(),
+        );
+        assert_eq!(
+            Err("There is no update 99, the updates are numbered 1 to 6.".to_string()),
+            dot_script(include_str!("../input/demo.txt"), Some(99))
+        );
+        assert!(dot_script(include_str!("../input/demo.txt"), Some(0)).is_err());
+    }
+}
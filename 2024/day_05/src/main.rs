@@ -0,0 +1,71 @@
+use std::{env, process};
+
+const USAGE: &str = "Usage: day_2024_05 [--audit] [--dot [--update NUMBER]]";
+
+struct Options {
+    audit: bool,
+    dot: bool,
+    update: Option<usize>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        audit: false,
+        dot: false,
+        update: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--audit" => options.audit = true,
+            "--dot" => options.dot = true,
+            "--update" => {
+                let update = args
+                    .next()
+                    .ok_or("--update expects the number of an update.")?;
+                options.update = Some(
+                    update
+                        .parse()
+                        .map_err(|_| format!("Invalid update '{}'.", update))?,
+                );
+            }
+            arg => return Err(format!("Unknown argument '{}'.", arg)),
+        }
+    }
+    if options.update.is_some() && !options.dot {
+        return Err("--update needs --dot.".to_string());
+    }
+    Ok(options)
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let input = include_str!("../input/input.txt");
+
+    if options.dot {
+        if let Err(error) = day_2024_05::print_dot_script(input, options.update) {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+        return;
+    }
+    if options.audit {
+        print!("{}", day_2024_05::audit(input));
+        return;
+    }
+
+    println!(
+        "The sum of the middle pages of the valid orders is {}.",
+        day_2024_05::part_1(input)
+    );
+    println!(
+        "The sum of the middle pages of the invalid orders is {}.",
+        day_2024_05::part_2(input)
+    );
+}
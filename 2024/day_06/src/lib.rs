@@ -0,0 +1,618 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display, Write},
+};
+
+use rayon::prelude::*;
+
+/// The mapped area as a grid of cells, so that looking up a cell takes constant time.
+struct Map {
+    blocked: Vec<bool>,
+    guards: Vec<Guard>,
+    height: i32,
+    width: i32,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Coordinates {
+    x: i32,
+    y: i32,
+}
+
+impl Display for Coordinates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+/// A guard at its starting position, facing the way it first walks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Guard {
+    position: Coordinates,
+    direction: Direction,
+}
+
+impl Map {
+    fn new(data: &str) -> Self {
+        let mut blocked = vec![];
+        let mut guards = vec![];
+        let mut height = 0;
+        for (y, line) in data.lines().enumerate() {
+            for (x, cell) in line.bytes().enumerate() {
+                if let Some(direction) = Direction::from_symbol(cell) {
+                    guards.push(Guard {
+                        position: Coordinates {
+                            x: x as i32,
+                            y: y as i32,
+                        },
+                        direction,
+                    });
+                }
+                blocked.push(cell == b'#');
+            }
+            height += 1;
+        }
+        Self {
+            width: blocked.len() as i32 / height,
+            blocked,
+            guards,
+            height,
+        }
+    }
+    fn index(&self, coordinates: &Coordinates) -> usize {
+        (coordinates.x + coordinates.y * self.width) as usize
+    }
+    fn is_blocked(&self, coordinates: &Coordinates) -> bool {
+        self.on_map(coordinates) && self.blocked[self.index(coordinates)]
+    }
+    fn on_map(&self, coordinates: &Coordinates) -> bool {
+        (0..self.width).contains(&coordinates.x) && (0..self.height).contains(&coordinates.y)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Right,
+    Direction::Down,
+    Direction::Left,
+];
+
+impl Direction {
+    fn from_symbol(symbol: u8) -> Option<Self> {
+        match symbol {
+            b'^' => Some(Direction::Up),
+            b'>' => Some(Direction::Right),
+            b'v' => Some(Direction::Down),
+            b'<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+    fn symbol(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+    fn index(&self) -> usize {
+        match self {
+            Direction::Up => 0,
+            Direction::Right => 1,
+            Direction::Down => 2,
+            Direction::Left => 3,
+        }
+    }
+    fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::Right => Direction::Down,
+        }
+    }
+}
+
+impl Coordinates {
+    fn go_steps(self, direction: &Direction, steps: i32) -> Self {
+        match direction {
+            Direction::Up => Self {
+                y: self.y - steps,
+                ..self
+            },
+            Direction::Down => Self {
+                y: self.y + steps,
+                ..self
+            },
+            Direction::Left => Self {
+                x: self.x - steps,
+                ..self
+            },
+            Direction::Right => Self {
+                x: self.x + steps,
+                ..self
+            },
+        }
+    }
+    fn go(self, direction: &Direction) -> Self {
+        self.go_steps(direction, 1)
+    }
+}
+
+/// For every cell and direction the cell in front of the next obstacle, where the guard turns,
+/// or `None` if the guard leaves the mapped area.
+struct Jumps {
+    stops: [Vec<Option<Coordinates>>; 4],
+}
+
+impl Jumps {
+    fn new(map: &Map) -> Self {
+        let cells = (map.width * map.height) as usize;
+        let mut stops = [
+            vec![None; cells],
+            vec![None; cells],
+            vec![None; cells],
+            vec![None; cells],
+        ];
+        for direction in DIRECTIONS {
+            // Walking the cells against the direction, the stop of the cell ahead is known
+            // before the stop of a cell.
+            let mut cells = (0..map.height)
+                .flat_map(|y| (0..map.width).map(move |x| Coordinates { x, y }))
+                .collect::<Vec<_>>();
+            if matches!(direction, Direction::Down | Direction::Right) {
+                cells.reverse();
+            }
+            let stops = &mut stops[direction.index()];
+            for cell in cells {
+                let ahead = cell.go(&direction);
+                stops[map.index(&cell)] = if map.is_blocked(&ahead) {
+                    Some(cell)
+                } else if map.on_map(&ahead) {
+                    stops[map.index(&ahead)]
+                } else {
+                    None
+                };
+            }
+        }
+        Self { stops }
+    }
+
+    /// Where the guard walking from `position` turns next, taking the extra obstruction into
+    /// account.
+    fn stop(
+        &self,
+        map: &Map,
+        position: &Coordinates,
+        direction: &Direction,
+        obstruction: &Coordinates,
+    ) -> Option<Coordinates> {
+        let stop = self.stops[direction.index()][map.index(position)];
+        let Some(distance) = steps_between(position, obstruction, direction) else {
+            return stop;
+        };
+        match stop {
+            Some(stop) if steps_between(position, &stop, direction) < Some(distance) => Some(stop),
+            _ => Some(position.go_steps(direction, distance - 1)),
+        }
+    }
+}
+
+/// The number of steps in the direction from `from` to `to`, if `to` lies ahead.
+fn steps_between(from: &Coordinates, to: &Coordinates, direction: &Direction) -> Option<i32> {
+    let steps = match direction {
+        Direction::Up if from.x == to.x => from.y - to.y,
+        Direction::Down if from.x == to.x => to.y - from.y,
+        Direction::Left if from.y == to.y => from.x - to.x,
+        Direction::Right if from.y == to.y => to.x - from.x,
+        _ => return None,
+    };
+    (steps > 0).then_some(steps)
+}
+
+/// Whether the guard walks in a loop once the obstruction is placed. The guard jumps from one
+/// turn to the next and is in a loop when it turns at the same place in the same direction
+/// twice.
+fn is_loop(map: &Map, jumps: &Jumps, guard: &Guard, obstruction: &Coordinates) -> bool {
+    let mut turning_points = HashSet::new();
+    let mut position = guard.position;
+    let mut direction = guard.direction;
+    while let Some(stop) = jumps.stop(map, &position, &direction, obstruction) {
+        if !turning_points.insert((stop, direction)) {
+            return true;
+        }
+        position = stop;
+        direction = direction.turn_right();
+    }
+    false
+}
+
+fn unique_coordinates(positions: &[Coordinates]) -> HashSet<Coordinates> {
+    HashSet::from_iter(positions.iter().cloned())
+}
+
+fn take_step(
+    map: &Map,
+    position: &Coordinates,
+    direction: &Direction,
+    obstruction: Option<&Coordinates>,
+) -> Option<(Coordinates, Direction)> {
+    let mut new_position = position.go(direction);
+    let mut new_direction = *direction;
+    if map.is_blocked(&new_position) || obstruction == Some(&new_position) {
+        new_position = *position;
+        new_direction = direction.turn_right();
+    } else if !map.on_map(&new_position) {
+        return None;
+    }
+    Some((new_position, new_direction))
+}
+
+/// The positions of a guard until it leaves the mapped area.
+struct Walk {
+    positions: Vec<Coordinates>,
+    /// The guard walks in a loop without any obstruction and never leaves.
+    trapped: bool,
+}
+
+fn walk(map: &Map, guard: &Guard) -> Walk {
+    let mut position = guard.position;
+    let mut direction = guard.direction;
+    let mut positions = vec![];
+    let mut seen = HashSet::new();
+    loop {
+        if !seen.insert((position, direction)) {
+            return Walk {
+                positions,
+                trapped: true,
+            };
+        }
+        positions.push(position);
+        if let Some((new_position, new_direction)) = take_step(map, &position, &direction, None) {
+            position = new_position;
+            direction = new_direction;
+        } else {
+            break;
+        }
+    }
+    Walk {
+        positions,
+        trapped: false,
+    }
+}
+
+/// The positions of an obstruction which make the guard walk in a loop, ordered by row and
+/// column. The obstruction is never placed where a guard starts, and there is none for a guard
+/// which is trapped already.
+fn trapping_obstructions(map: &Map, jumps: &Jumps, guard: &Guard) -> Vec<Coordinates> {
+    let walk = walk(map, guard);
+    if walk.trapped {
+        return vec![];
+    }
+    // Only an obstruction on the path of the guard changes the walk.
+    let mut candidates = unique_coordinates(&walk.positions);
+    for other in &map.guards {
+        candidates.remove(&other.position);
+    }
+    let mut obstructions = candidates
+        .into_par_iter()
+        .filter(|obstruction| is_loop(map, jumps, guard, obstruction))
+        .collect::<Vec<_>>();
+    obstructions.sort_by_key(|obstruction| (obstruction.y, obstruction.x));
+    obstructions
+}
+
+/// How a guard walks into a loop around an obstruction.
+struct Loop {
+    /// Every position and direction of the guard until the walk repeats.
+    path: Vec<(Coordinates, Direction)>,
+    /// The index in the path where the loop starts.
+    start: usize,
+}
+
+impl Loop {
+    /// Walks the guard step by step until it is back in a state it was in before.
+    fn find(map: &Map, guard: &Guard, obstruction: &Coordinates) -> Option<Self> {
+        let mut seen = HashMap::new();
+        let mut path = vec![];
+        let mut state = (guard.position, guard.direction);
+        loop {
+            if let Some(start) = seen.insert(state, path.len()) {
+                return Some(Self { path, start });
+            }
+            path.push(state);
+            state = take_step(map, &state.0, &state.1, Some(obstruction))?;
+        }
+    }
+
+    /// Pairs of successive states, the loop closes with a pair from its end to its start.
+    fn transitions(
+        &self,
+    ) -> impl Iterator<Item = (&(Coordinates, Direction), &(Coordinates, Direction))> {
+        self.path
+            .iter()
+            .zip(self.path.iter().skip(1).chain(Some(&self.path[self.start])))
+    }
+
+    /// The number of steps the guard takes before it enters the loop.
+    fn steps_before(&self) -> usize {
+        self.transitions()
+            .take(self.start)
+            .filter(|(from, to)| from.0 != to.0)
+            .count()
+    }
+
+    /// The number of steps of one round of the loop.
+    fn length(&self) -> usize {
+        self.transitions()
+            .skip(self.start)
+            .filter(|(from, to)| from.0 != to.0)
+            .count()
+    }
+
+    /// The positions where the guard turns in one round of the loop.
+    fn turning_points(&self) -> Vec<Coordinates> {
+        let mut turning_points = self
+            .transitions()
+            .skip(self.start)
+            .filter(|(from, to)| from.1 != to.1)
+            .map(|(from, _)| from.0)
+            .collect::<Vec<_>>();
+        // Turning around takes two turns on the same position.
+        turning_points.dedup();
+        turning_points
+    }
+
+    /// Draws the walk on the map as in the puzzle, with `|` and `-` where the guard walks up or
+    /// down and left or right, `+` where it does both and `O` for the obstruction.
+    fn render(&self, input: &str, map: &Map, guard: &Guard, obstruction: &Coordinates) -> String {
+        const VERTICAL: u8 = 1;
+        const HORIZONTAL: u8 = 2;
+        let mut marks = vec![0; map.blocked.len()];
+        for (position, direction) in &self.path {
+            marks[map.index(position)] |= match direction {
+                Direction::Up | Direction::Down => VERTICAL,
+                Direction::Left | Direction::Right => HORIZONTAL,
+            };
+        }
+
+        let mut rendering = String::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, cell) in line.chars().enumerate() {
+                let position = Coordinates {
+                    x: x as i32,
+                    y: y as i32,
+                };
+                let cell = if position == *obstruction {
+                    'O'
+                } else if position == guard.position {
+                    guard.direction.symbol()
+                } else {
+                    match marks[map.index(&position)] {
+                        VERTICAL => '|',
+                        HORIZONTAL => '-',
+                        0 => cell,
+                        _ => '+',
+                    }
+                };
+                rendering.push(cell);
+            }
+            rendering.push('\n');
+        }
+        rendering
+    }
+}
+
+/// Describes for every obstruction which traps a guard how the guard walks into the loop.
+pub fn loop_diagnostics(input: &str) -> String {
+    let map = Map::new(input);
+    let jumps = Jumps::new(&map);
+
+    let mut diagnostics = String::new();
+    for (index, guard) in map.guards.iter().enumerate() {
+        let loops = trapping_obstructions(&map, &jumps, guard)
+            .into_par_iter()
+            .map(|obstruction| (obstruction, Loop::find(&map, guard, &obstruction).unwrap()))
+            .collect::<Vec<_>>();
+        for (obstruction, guard_loop) in loops {
+            let turning_points = guard_loop
+                .turning_points()
+                .iter()
+                .map(|position| position.to_string())
+                .collect::<Vec<_>>();
+            writeln!(
+                diagnostics,
+                "Obstruction {} traps guard {} after {} steps in a loop of {} steps turning at {}.",
+                obstruction,
+                index + 1,
+                guard_loop.steps_before(),
+                guard_loop.length(),
+                turning_points.join(" ")
+            )
+            .unwrap();
+        }
+    }
+    diagnostics
+}
+
+/// Draws the walk of the guard with the given number, starting at 1, into the loop around
+/// the obstruction at `(x, y)`. Returns `None` if there is no such guard or the obstruction
+/// does not trap it.
+pub fn render_loop(input: &str, guard: usize, (x, y): (i32, i32)) -> Option<String> {
+    let map = Map::new(input);
+    let guard = map.guards.get(guard.checked_sub(1)?)?;
+    let obstruction = Coordinates { x, y };
+    if !map.on_map(&obstruction)
+        || map.is_blocked(&obstruction)
+        || map.guards.iter().any(|other| other.position == obstruction)
+    {
+        return None;
+    }
+    let guard_loop = Loop::find(&map, guard, &obstruction)?;
+    Some(guard_loop.render(input, &map, guard, &obstruction))
+}
+
+/// Lists for every guard the cells it visits and the obstructions which trap it.
+pub fn report(input: &str) -> String {
+    let map = Map::new(input);
+    let jumps = Jumps::new(&map);
+
+    let mut report = String::new();
+    let mut visited = HashSet::new();
+    for (index, guard) in map.guards.iter().enumerate() {
+        let walk = walk(&map, guard);
+        let cells = unique_coordinates(&walk.positions);
+        writeln!(
+            report,
+            "Guard {} starts at {} facing {}.",
+            index + 1,
+            guard.position,
+            guard.direction.symbol()
+        )
+        .unwrap();
+        writeln!(report, "  It visits {} cells.", cells.len()).unwrap();
+        if walk.trapped {
+            writeln!(report, "  It is trapped without any obstruction.").unwrap();
+            visited.extend(cells);
+            continue;
+        }
+        let obstructions = trapping_obstructions(&map, &jumps, guard)
+            .iter()
+            .map(|obstruction| obstruction.to_string())
+            .collect::<Vec<_>>();
+        if obstructions.is_empty() {
+            writeln!(report, "  No obstruction traps it.").unwrap();
+        } else {
+            writeln!(
+                report,
+                "  {} obstructions trap it: {}",
+                obstructions.len(),
+                obstructions.join(" ")
+            )
+            .unwrap();
+        }
+        visited.extend(cells);
+    }
+    writeln!(report, "All guards visit {} cells.", visited.len()).unwrap();
+    report
+}
+
+pub fn part_1(input: &str) -> String {
+    let map = Map::new(input);
+
+    let visited = map
+        .guards
+        .iter()
+        .flat_map(|guard| walk(&map, guard).positions)
+        .collect::<HashSet<_>>();
+    visited.len().to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let map = Map::new(input);
+    let jumps = Jumps::new(&map);
+
+    let obstructions = map
+        .guards
+        .iter()
+        .flat_map(|guard| trapping_obstructions(&map, &jumps, guard))
+        .collect::<HashSet<_>>();
+    obstructions.len().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jumps() {
+        let map = Map::new(include_str!("../input/demo.txt"));
+        let jumps = Jumps::new(&map);
+        let guard = map.guards[0];
+        let start = guard.position;
+        let nowhere = Coordinates { x: -1, y: -1 };
+        assert_eq!(
+            Some(Coordinates { x: 4, y: 1 }),
+            jumps.stop(&map, &start, &Direction::Up, &nowhere)
+        );
+        assert_eq!(
+            Some(Coordinates { x: 4, y: 4 }),
+            jumps.stop(&map, &start, &Direction::Up, &Coordinates { x: 4, y: 3 })
+        );
+        // An obstruction behind the obstacle changes nothing.
+        assert_eq!(
+            Some(Coordinates { x: 4, y: 1 }),
+            jumps.stop(&map, &start, &Direction::Up, &Coordinates { x: 4, y: 0 })
+        );
+        assert_eq!(None, jumps.stop(&map, &start, &Direction::Down, &nowhere));
+        assert!(is_loop(&map, &jumps, &guard, &Coordinates { x: 3, y: 6 }));
+        assert!(!is_loop(&map, &jumps, &guard, &Coordinates { x: 4, y: 3 }));
+    }
+
+    #[test]
+    fn test_report() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/guards.txt"),
+            &report(include_str!("../input/guards.txt")),
+        );
+    }
+
+    #[test]
+    fn test_loop() {
+        let map = Map::new(include_str!("../input/demo.txt"));
+        let guard = map.guards[0];
+        let guard_loop = Loop::find(&map, &guard, &Coordinates { x: 3, y: 6 }).unwrap();
+        assert_eq!(0, guard_loop.steps_before());
+        assert_eq!(18, guard_loop.length());
+        assert_eq!(
+            vec![
+                Coordinates { x: 4, y: 1 },
+                Coordinates { x: 8, y: 1 },
+                Coordinates { x: 8, y: 6 },
+                Coordinates { x: 4, y: 6 },
+            ],
+            guard_loop.turning_points()
+        );
+        assert!(Loop::find(&map, &guard, &Coordinates { x: 4, y: 3 }).is_none());
+    }
+
+    #[test]
+    fn test_loop_diagnostics() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/loops.txt"),
+            &loop_diagnostics(include_str!("../input/demo.txt")),
+        );
+    }
+
+    #[test]
+    fn test_render_loop() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/loop.txt"),
+            &render_loop(include_str!("../input/demo.txt"), 1, (3, 6)).unwrap(),
+        );
+        assert_eq!(
+            None,
+            render_loop(include_str!("../input/demo.txt"), 1, (4, 3))
+        );
+    }
+
+    #[test]
+    fn test_trapped_guard() {
+        let input = ".#..\n.^.#\n#...\n..#.\n";
+        let map = Map::new(input);
+        let walk = walk(&map, &map.guards[0]);
+        assert!(walk.trapped);
+        assert_eq!(4, unique_coordinates(&walk.positions).len());
+        assert_eq!("4", part_1(input));
+        assert_eq!("0", part_2(input));
+        assert!(report(input).contains("It is trapped without any obstruction."));
+    }
+}
@@ -0,0 +1,98 @@
+use std::{env, fs, process};
+
+const USAGE: &str =
+    "Usage: day_2024_06 [--input FILE] [--report | --loops | --loop X,Y [--guard NUMBER]]";
+
+struct Options {
+    input: Option<String>,
+    report: bool,
+    loops: bool,
+    render_loop: Option<(i32, i32)>,
+    guard: usize,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        input: None,
+        report: false,
+        loops: false,
+        render_loop: None,
+        guard: 1,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => options.input = Some(args.next().ok_or("--input expects a file.")?),
+            "--report" => options.report = true,
+            "--loops" => options.loops = true,
+            "--loop" => {
+                let position = args.next().ok_or("--loop expects a position X,Y.")?;
+                options.render_loop = Some(
+                    position
+                        .split_once(',')
+                        .and_then(|(x, y)| Some((x.parse().ok()?, y.parse().ok()?)))
+                        .ok_or(format!("Invalid position '{}'.", position))?,
+                );
+            }
+            "--guard" => {
+                let guard = args
+                    .next()
+                    .ok_or("--guard expects the number of a guard.")?;
+                options.guard = guard
+                    .parse()
+                    .map_err(|_| format!("Invalid guard '{}'.", guard))?;
+            }
+            arg => return Err(format!("Unknown argument '{}'.", arg)),
+        }
+    }
+    Ok(options)
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let input = match &options.input {
+        Some(path) => fs::read_to_string(path).unwrap_or_else(|error| {
+            eprintln!("Cannot read {}: {}", path, error);
+            process::exit(1);
+        }),
+        None => include_str!("../input/input.txt").to_string(),
+    };
+    let input = input.as_str();
+
+    if let Some(obstruction) = options.render_loop {
+        match day_2024_06::render_loop(input, options.guard, obstruction) {
+            Some(rendering) => print!("{}", rendering),
+            None => {
+                eprintln!(
+                    "An obstruction at ({},{}) does not trap guard {}.",
+                    obstruction.0, obstruction.1, options.guard
+                );
+                process::exit(1);
+            }
+        }
+        return;
+    }
+    if options.loops {
+        print!("{}", day_2024_06::loop_diagnostics(input));
+        return;
+    }
+    if options.report {
+        print!("{}", day_2024_06::report(input));
+        return;
+    }
+
+    println!(
+        "The guards visit {} positions before leaving the mapped area.",
+        day_2024_06::part_1(input)
+    );
+    println!(
+        "There are {} different positions to place an obstruction",
+        day_2024_06::part_2(input)
+    );
+}
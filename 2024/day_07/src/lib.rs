@@ -38,8 +38,7 @@ fn possible(equation: &&Equation) -> bool {
     possible_equation(equation.result, &equation.numbers)
 }
 
-fn main() {
-    let input = include_str!("../input/demo.txt");
+pub fn part_1(input: &str) -> String {
     let equations = input
         .lines()
         .map(|line| {
@@ -58,8 +57,5 @@ fn main() {
         .filter(possible)
         .map(|equation| equation.result)
         .sum::<i64>();
-    println!(
-        "The total calibration result ist {}.",
-        total_calibration_result
-    );
+    total_calibration_result.to_string()
 }
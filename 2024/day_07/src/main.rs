@@ -0,0 +1,8 @@
+fn main() {
+    let input = include_str!("../input/demo.txt");
+
+    println!(
+        "The total calibration result ist {}.",
+        day_2024_07::part_1(input)
+    );
+}
@@ -51,7 +51,8 @@ fn antinodes(positions: &[(i32, i32)]) -> Vec<(i32, i32)> {
             .filter(move |b| a != *b)
             .map(move |b| (a, b))
     });
-    let antinodes = pairs
+
+    pairs
         .flat_map(|((x1, y1), (x2, y2))| {
             let nx1 = x1 - (x2 - x1);
             let nx2 = x2 + (x2 - x1);
@@ -59,9 +60,7 @@ fn antinodes(positions: &[(i32, i32)]) -> Vec<(i32, i32)> {
             let ny2 = y2 + (y2 - y1);
             [(nx1, ny1), (nx2, ny2)]
         })
-        .collect::<Vec<_>>();
-
-    antinodes
+        .collect::<Vec<_>>()
 }
 
 fn harmonic_antinodes(positions: &[(i32, i32)]) -> Vec<((i32, i32), (i32, i32))> {
@@ -71,19 +70,18 @@ fn harmonic_antinodes(positions: &[(i32, i32)]) -> Vec<((i32, i32), (i32, i32))>
             .filter(move |b| a != *b)
             .map(move |b| (a, b))
     });
-    let harmonic_antinodes = pairs
+
+    pairs
         .map(|((x1, y1), (x2, y2))| {
             let o_x = x2 - x1;
             let o_y = y2 - y1;
             ((*x1, *y1), (o_x, o_y))
         })
-        .collect::<Vec<_>>();
-    harmonic_antinodes
+        .collect::<Vec<_>>()
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
-    let map = Map::from_str(&input).unwrap();
+pub fn part_1(input: &str) -> String {
+    let map = Map::from_str(input).unwrap();
 
     let unique_antinodes = map
         .antennas
@@ -91,10 +89,11 @@ fn main() {
         .flat_map(|positions| antinodes(positions))
         .filter(|(x, y)| (0..map.width).contains(x) && (0..map.height).contains(y))
         .collect::<HashSet<_>>();
-    println!(
-        "There are {} unique locations containing an antinode.",
-        unique_antinodes.len()
-    );
+    unique_antinodes.len().to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let map = Map::from_str(input).unwrap();
 
     let n = map.width.max(map.height);
     let unique_harmonic_antinodes = map
@@ -106,8 +105,5 @@ fn main() {
         })
         .filter(|(x, y)| (0..map.width).contains(x) && (0..map.height).contains(y))
         .collect::<HashSet<_>>();
-    println!(
-        "There are {} unique locations containing an antinode.",
-        unique_harmonic_antinodes.len()
-    );
+    unique_harmonic_antinodes.len().to_string()
 }
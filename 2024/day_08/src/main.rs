@@ -0,0 +1,12 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "There are {} unique locations containing an antinode.",
+        day_2024_08::part_1(input)
+    );
+    println!(
+        "There are {} unique locations containing an antinode.",
+        day_2024_08::part_2(input)
+    );
+}
@@ -1,4 +1,4 @@
-use std::{iter, usize};
+use std::iter;
 
 #[derive(Debug, Clone)]
 struct File {
@@ -42,10 +42,7 @@ fn defrag_blockwise(data: &[Type]) -> Vec<Type> {
                     length: 1,
                     ..f.clone()
                 }),
-                Type::Space(s) => Type::Space(Space {
-                    length: 1,
-                    ..s.clone()
-                }),
+                Type::Space(_) => Type::Space(Space { length: 1 }),
             };
 
             iter::repeat_n(t, length)
@@ -116,10 +113,8 @@ fn calc_checksum(data: &[Type]) -> usize {
         .sum::<usize>()
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
-
-    let data = input
+fn parse_input(input: &str) -> Vec<Type> {
+    input
         .chars()
         .map(|c| c.to_digit(10).unwrap() as usize)
         .scan((true, 0), |(is_file, id), length| {
@@ -133,15 +128,21 @@ fn main() {
             *is_file = !*is_file;
             Some(t)
         })
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
+
+pub fn part_1(input: &str) -> String {
+    let data = parse_input(input);
 
     let data_1 = defrag_blockwise(&data);
     let checksum_1 = calc_checksum(&data_1);
+    checksum_1.to_string()
+}
 
-    println!("The first checksum is {}.", checksum_1);
+pub fn part_2(input: &str) -> String {
+    let data = parse_input(input);
 
     let data_2 = defrag_filewise(&data);
     let checksum_2 = calc_checksum(&data_2);
-
-    println!("The second checksum is {}.", checksum_2);
+    checksum_2.to_string()
 }
@@ -0,0 +1,6 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!("The first checksum is {}.", day_2024_09::part_1(input));
+    println!("The second checksum is {}.", day_2024_09::part_2(input));
+}
@@ -38,7 +38,7 @@ impl FromStr for Map {
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let data = input
             .chars()
-            .filter_map(|c| c.to_digit(10).and_then(|c| Some(c as u8)))
+            .filter_map(|c| c.to_digit(10).map(|c| c as u8))
             .collect::<Vec<_>>();
         let width = input.find('\n').unwrap();
         Ok(Self { data, width })
@@ -54,17 +54,17 @@ impl Iterator for MapIterator<'_> {
     type Item = (Coordinates, u8);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.map.data.get(self.index).and_then(|value| {
+        self.map.data.get(self.index).map(|value| {
             let x = self.index % self.map.width;
             let y = self.index / self.map.width;
             self.index += 1;
-            Some((Coordinates { x, y }, *value))
+            (Coordinates { x, y }, *value)
         })
     }
 }
 
 impl Map {
-    fn iter(&self) -> MapIterator {
+    fn iter(&self) -> MapIterator<'_> {
         MapIterator {
             map: self,
             index: 0,
@@ -129,14 +129,16 @@ impl Map {
     }
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
-    let map = Map::from_str(&input).unwrap();
-    let paths = map
-        .iter()
+fn find_all_paths(map: &Map) -> Vec<Vec<Vec<Coordinates>>> {
+    map.iter()
         .filter(|(_, v)| *v == 0)
         .filter_map(|(coordinates, v)| map.find_paths(&coordinates, v))
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
+
+pub fn part_1(input: &str) -> String {
+    let map = Map::from_str(input).unwrap();
+    let paths = find_all_paths(&map);
 
     let scores = paths
         .iter()
@@ -146,11 +148,13 @@ fn main() {
         })
         .collect::<Vec<_>>();
     let scores = scores.iter().sum::<usize>();
-    println!(
-        "The sum of the scores of all trailheads on the map is {}.",
-        scores
-    );
+    scores.to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let map = Map::from_str(input).unwrap();
+    let paths = find_all_paths(&map);
 
     let ratings = paths.iter().map(|path| path.len()).sum::<usize>();
-    println!("The sum of the ratings is {}.", ratings);
+    ratings.to_string()
 }
@@ -0,0 +1,9 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "The sum of the scores of all trailheads on the map is {}.",
+        day_2024_10::part_1(input)
+    );
+    println!("The sum of the ratings is {}.", day_2024_10::part_2(input));
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+type Cache = HashMap<(u64, i32), usize>;
+
+fn determine_stone(number: u64, iteration: i32, cache: &mut Cache) -> usize {
+    if let Some(count) = cache.get(&(number, iteration)) {
+        return *count;
+    }
+
+    let result = if iteration == 0 {
+        1
+    } else if number == 0 {
+        determine_stone(1, iteration - 1, cache)
+    } else {
+        let stone_length = ((number as f64).log10() as u32) + 1;
+        if stone_length.is_multiple_of(2) {
+            let divisor = 10u64.pow(stone_length / 2);
+            let a = number / divisor;
+            let b = number % divisor;
+            determine_stone(a, iteration - 1, cache) + determine_stone(b, iteration - 1, cache)
+        } else {
+            determine_stone(number * 2024, iteration - 1, cache)
+        }
+    };
+
+    cache.insert((number, iteration), result);
+    result
+}
+
+fn parse_stones(input: &str) -> Vec<u64> {
+    input
+        .split_ascii_whitespace()
+        .map(|s| s.parse::<u64>().unwrap())
+        .collect::<Vec<_>>()
+}
+
+fn count_stones(stones: &[u64], blinks: i32) -> usize {
+    let mut cache = Cache::new();
+
+    stones
+        .iter()
+        .map(|number| determine_stone(*number, blinks, &mut cache))
+        .sum::<usize>()
+}
+
+pub fn part_1(input: &str) -> String {
+    let stones = parse_stones(input);
+    count_stones(&stones, 25).to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let stones = parse_stones(input);
+    count_stones(&stones, 75).to_string()
+}
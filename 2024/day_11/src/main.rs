@@ -0,0 +1,12 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "After 25 blinks there are {} stones.",
+        day_2024_11::part_1(input)
+    );
+    println!(
+        "After 75 blinks there are {} stones.",
+        day_2024_11::part_2(input)
+    );
+}
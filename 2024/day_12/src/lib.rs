@@ -255,8 +255,7 @@ impl Sides for Region {
     }
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+pub fn part_1(input: &str) -> String {
     let map = Map::from_str(input).unwrap();
 
     let regions = map.regions();
@@ -268,8 +267,13 @@ fn main() {
         .iter()
         .map(|region| region.area() * region.perimeter())
         .sum::<usize>();
-    println!("The total price of fencing all regions is {}.", price);
+    price.to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let map = Map::from_str(input).unwrap();
 
+    let regions = map.regions();
     let price = regions
         .iter()
         .map(|region| {
@@ -278,8 +282,5 @@ fn main() {
             area * sides
         })
         .sum::<usize>();
-    println!(
-        "The price of fencing all regions by there sides is {}.",
-        price
-    );
+    price.to_string()
 }
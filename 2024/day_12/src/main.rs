@@ -0,0 +1,12 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "The total price of fencing all regions is {}.",
+        day_2024_12::part_1(input)
+    );
+    println!(
+        "The price of fencing all regions by there sides is {}.",
+        day_2024_12::part_2(input)
+    );
+}
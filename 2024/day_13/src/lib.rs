@@ -22,29 +22,38 @@ fn resolve(machine: &ClawMachine) -> Option<(i64, i64)> {
     Some((x, y))
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+fn parse_input(input: &str) -> Vec<ClawMachine> {
     let regex = Regex::new(r"\d+").unwrap();
 
     let numbers = regex
         .find_iter(input)
         .map(|x| x.as_str().parse::<i64>().unwrap())
         .collect::<Vec<_>>();
-    let clawmachines = numbers
+    numbers
         .chunks_exact(6)
         .map(|value| ClawMachine {
             button_a: (value[0], value[1]),
             button_b: (value[2], value[3]),
             prize: (value[4], value[5]),
         })
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
 
-    let tokens = clawmachines
+fn tokens(clawmachines: &[ClawMachine]) -> i64 {
+    clawmachines
         .iter()
-        .filter_map(|claw_machine| resolve(&claw_machine))
+        .filter_map(resolve)
         .map(|(a, b)| a * 3 + b)
-        .sum::<i64>();
-    println!("To win all prizes, you have to spend {} tokens.", tokens);
+        .sum::<i64>()
+}
+
+pub fn part_1(input: &str) -> String {
+    let clawmachines = parse_input(input);
+    tokens(&clawmachines).to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let clawmachines = parse_input(input);
 
     let modified_clawmachines = clawmachines
         .iter()
@@ -56,10 +65,5 @@ fn main() {
             ..*claw_machine
         })
         .collect::<Vec<_>>();
-    let tokens = modified_clawmachines
-        .iter()
-        .filter_map(|claw_machine| resolve(&claw_machine))
-        .map(|(a, b)| a * 3 + b)
-        .sum::<i64>();
-    println!("To win all prizes, you have to spend {} tokens.", tokens);
+    tokens(&modified_clawmachines).to_string()
 }
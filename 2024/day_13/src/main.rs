@@ -0,0 +1,12 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "To win all prizes, you have to spend {} tokens.",
+        day_2024_13::part_1(input)
+    );
+    println!(
+        "To win all prizes, you have to spend {} tokens.",
+        day_2024_13::part_2(input)
+    );
+}
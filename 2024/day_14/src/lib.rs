@@ -1,5 +1,9 @@
 use std::str::FromStr;
 
+#[cfg(feature = "visualize")]
+use std::{fs, io, path::Path};
+
+#[cfg(feature = "visualize")]
 use bmp::Image;
 
 struct XYPair<T> {
@@ -63,21 +67,21 @@ fn quadrants(positions: &[XYPair<u32>], room: &XYPair<u32>) -> [usize; 4] {
     [top_left, top_right, bottom_left, bottom_right]
 }
 
-fn show_robots(positions: &[XYPair<u32>], room: &XYPair<u32>, iteration: u32) {
-    let mut image = Image::new(room.x as u32, room.y);
+#[cfg(feature = "visualize")]
+fn show_robots(
+    positions: &[XYPair<u32>],
+    room: &XYPair<u32>,
+    iteration: u32,
+    output_dir: &Path,
+) -> io::Result<()> {
+    let mut image = Image::new(room.x, room.y);
     for position in positions {
         image.set_pixel(position.x, position.y, bmp::consts::WHITE);
     }
-    image
-        .save(format!(
-            "/workspaces/rust/day_14/images/it_{}.bmp",
-            iteration
-        ))
-        .unwrap();
+    image.save(output_dir.join(format!("it_{}.bmp", iteration)))
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+fn parse_input(input: &str) -> (Vec<Robot>, XYPair<u32>) {
     let robots = input
         .lines()
         .map(|line| Robot::from_str(line).unwrap())
@@ -87,6 +91,11 @@ fn main() {
     } else {
         XYPair { x: 101, y: 103 }
     };
+    (robots, room)
+}
+
+pub fn part_1(input: &str) -> String {
+    let (robots, room) = parse_input(input);
 
     let moved_robots = robots
         .iter()
@@ -95,13 +104,20 @@ fn main() {
     let per_quadrant = quadrants(&moved_robots, &room);
 
     let safety_factor = per_quadrant.iter().product::<usize>();
-    println!("The safety factor after 100 secondes is {}.", safety_factor);
+    safety_factor.to_string()
+}
+
+#[cfg(feature = "visualize")]
+pub fn show_all_robots(input: &str, output_dir: &Path) -> io::Result<()> {
+    let (robots, room) = parse_input(input);
+    fs::create_dir_all(output_dir)?;
 
     for iteration in 0..10000 {
         let moved_robots = robots
             .iter()
             .map(|robot| move_robot(robot, &room, iteration))
             .collect::<Vec<_>>();
-        show_robots(&moved_robots, &room, iteration);
+        show_robots(&moved_robots, &room, iteration, output_dir)?;
     }
+    Ok(())
 }
@@ -0,0 +1,69 @@
+use std::{env, path::PathBuf, process};
+
+const USAGE: &str = "Usage: day_2024_14 [--visualize] [--output-dir DIR]";
+
+struct Options {
+    visualize: bool,
+    output_dir: PathBuf,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        visualize: false,
+        output_dir: PathBuf::from("images"),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--visualize" => options.visualize = true,
+            "--output-dir" => {
+                let output_dir = args.next().ok_or("--output-dir expects a directory.")?;
+                options.output_dir = PathBuf::from(output_dir);
+            }
+            arg => return Err(format!("Unknown argument '{}'.", arg)),
+        }
+    }
+    Ok(options)
+}
+
+#[cfg(feature = "visualize")]
+fn visualize(input: &str, options: &Options) {
+    if let Err(error) = day_2024_14::show_all_robots(input, &options.output_dir) {
+        eprintln!(
+            "Cannot write the images to {}: {}",
+            options.output_dir.display(),
+            error
+        );
+        process::exit(1);
+    }
+    println!(
+        "The images are written to {}.",
+        options.output_dir.display()
+    );
+}
+
+#[cfg(not(feature = "visualize"))]
+fn visualize(_input: &str, _options: &Options) {
+    eprintln!("Images need the 'visualize' feature: cargo run -p day_2024_14 --features visualize");
+    process::exit(2);
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "The safety factor after 100 secondes is {}.",
+        day_2024_14::part_1(input)
+    );
+
+    if options.visualize {
+        visualize(input, &options);
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    fmt::Display,
     ops::{Index, IndexMut},
     str::FromStr,
 };
@@ -92,7 +93,7 @@ impl Map {
                         return Some(map);
                     }
                 }
-                return None;
+                None
             }
             tile => {
                 let new_spot = xy.next(*direction);
@@ -127,6 +128,26 @@ impl Map {
     }
 }
 
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.tiles.chunks(self.width) {
+            let line = row
+                .iter()
+                .map(|tile| match tile {
+                    Tile::Wall => '#',
+                    Tile::WideBox(true) => '[',
+                    Tile::WideBox(false) => ']',
+                    Tile::Box => 'O',
+                    Tile::Space => '.',
+                    Tile::Robot => '@',
+                })
+                .collect::<String>();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
 impl Index<&XY> for Map {
     type Output = Tile;
 
@@ -181,18 +202,20 @@ fn moves(input: &str) -> Vec<Move> {
         .collect()
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+fn warehouse(input: &str) -> Map {
     let (map_str, moves_str) = input.split_once("\n\n").unwrap();
     let orig_map = Map::from_str(map_str).unwrap();
     let moves = moves(moves_str);
 
-    let map = moves
+    moves
         .iter()
-        .fold(orig_map.clone(), |map, direction| map.move_robot(direction));
+        .fold(orig_map.clone(), |map, direction| map.move_robot(direction))
+}
 
-    let sum_of_box_gps = map.sum_of_box_gps();
-    println!("The sum of the GPS coordinates is {}.", sum_of_box_gps);
+fn wide_warehouse(input: &str) -> Map {
+    let (map_str, moves_str) = input.split_once("\n\n").unwrap();
+    let orig_map = Map::from_str(map_str).unwrap();
+    let moves = moves(moves_str);
 
     let wide_tiles = orig_map
         .tiles
@@ -208,13 +231,50 @@ fn main() {
         width: orig_map.width * 2,
         tiles: wide_tiles,
     };
-    let map = moves
+    moves
         .iter()
-        .fold(wide_map.clone(), |map, direction| map.move_robot(direction));
+        .fold(wide_map.clone(), |map, direction| map.move_robot(direction))
+}
+
+pub fn render_warehouse(input: &str) -> String {
+    warehouse(input).to_string()
+}
+
+pub fn render_wide_warehouse(input: &str) -> String {
+    wide_warehouse(input).to_string()
+}
+
+pub fn part_1(input: &str) -> String {
+    let map = warehouse(input);
+
+    let sum_of_box_gps = map.sum_of_box_gps();
+    sum_of_box_gps.to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let map = wide_warehouse(input);
 
     let sum_of_box_gps = map.sum_of_box_gps();
-    println!(
-        "The sum of the GPS coordinates on the wide map is {}.",
-        sum_of_box_gps
-    );
+    sum_of_box_gps.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_warehouse() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/demo.txt"),
+            &render_warehouse(include_str!("../input/demo.txt")),
+        );
+    }
+
+    #[test]
+    fn test_render_wide_warehouse() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/demo_wide.txt"),
+            &render_wide_warehouse(include_str!("../input/demo.txt")),
+        );
+    }
 }
@@ -0,0 +1,12 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "The sum of the GPS coordinates is {}.",
+        day_2024_15::part_1(input)
+    );
+    println!(
+        "The sum of the GPS coordinates on the wide map is {}.",
+        day_2024_15::part_2(input)
+    );
+}
@@ -1,7 +1,6 @@
 use std::{
     collections::{HashMap, HashSet},
     str::FromStr,
-    usize,
 };
 
 #[derive(PartialEq, Clone, Copy)]
@@ -166,7 +165,7 @@ impl Maze {
         let index = position.y * self.width + position.x;
         *self.tiles.get(index).unwrap()
     }
-    fn next_states(&self, state: State) -> NextStates {
+    fn next_states(&self, state: State) -> NextStates<'_> {
         NextStates {
             maze: self,
             state,
@@ -177,8 +176,8 @@ impl Maze {
 
 fn h(state: State, end: XY) -> usize {
     let position = &state.position;
-    let manhatten = position.x.abs_diff(end.x) + position.y.abs_diff(end.y);
-    manhatten
+
+    position.x.abs_diff(end.x) + position.y.abs_diff(end.y)
 }
 
 fn reconstruct_paths(came_from: &HashMap<State, Vec<State>>, state: State) -> Vec<Vec<XY>> {
@@ -189,7 +188,7 @@ fn reconstruct_paths(came_from: &HashMap<State, Vec<State>>, state: State) -> Ve
             for new_path in &mut new_paths {
                 new_path.push(state.position);
             }
-            paths.extend_from_slice(&mut new_paths);
+            paths.extend_from_slice(&new_paths);
         }
         paths
     } else {
@@ -197,7 +196,7 @@ fn reconstruct_paths(came_from: &HashMap<State, Vec<State>>, state: State) -> Ve
     }
 }
 
-fn find_paths(maze: &Maze) -> Vec<Vec<XY>> {
+fn find_paths(maze: &Maze) -> (usize, Vec<Vec<XY>>) {
     let start = State {
         position: maze.start,
         direction: Direction::East,
@@ -216,10 +215,7 @@ fn find_paths(maze: &Maze) -> Vec<Vec<XY>> {
 
         if state.position == maze.end {
             let score = *g_score.get(&state).unwrap();
-            winning_score.get_or_insert_with(|| {
-                println!("The total cost from start to end is {}.", score);
-                score
-            });
+            winning_score.get_or_insert(score);
             winners.push(state);
         }
 
@@ -246,20 +242,22 @@ fn find_paths(maze: &Maze) -> Vec<Vec<XY>> {
         open_set.sort_unstable_by_key(|x| f_score.get(x).unwrap());
         open_set.reverse();
     }
-    winners
+    let paths = winners
         .iter()
         .flat_map(|winner| reconstruct_paths(&came_from, *winner))
-        .collect::<Vec<_>>()
+        .collect::<Vec<_>>();
+    (winning_score.unwrap(), paths)
+}
+
+pub fn part_1(input: &str) -> String {
+    let maze = Maze::from_str(input).unwrap();
+    let (score, _) = find_paths(&maze);
+    score.to_string()
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
-    let maze = Maze::from_str(&input).unwrap();
-    let paths = find_paths(&maze);
-    println!(
-        "There are {} different paths through the maze.",
-        paths.len()
-    );
+pub fn part_2(input: &str) -> String {
+    let maze = Maze::from_str(input).unwrap();
+    let (_, paths) = find_paths(&maze);
     let best_tiles = paths.iter().flatten().collect::<HashSet<_>>();
-    println!("There are {} best tiles on these paths.", best_tiles.len());
+    best_tiles.len().to_string()
 }
@@ -0,0 +1,12 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "The total cost from start to end is {}.",
+        day_2024_16::part_1(input)
+    );
+    println!(
+        "There are {} best tiles on these paths.",
+        day_2024_16::part_2(input)
+    );
+}
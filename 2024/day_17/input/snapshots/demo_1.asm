@@ -0,0 +1,3 @@
+ 0: adv 3   A = A >> 3
+ 2: out A   output A % 8
+ 4: jnz 0   if A != 0 jump to 0
@@ -1,6 +1,6 @@
 #![allow(non_snake_case, non_camel_case_types)]
 
-use std::ops::BitXorAssign;
+use std::{fmt::Display, ops::BitXorAssign};
 
 #[derive(Clone, Copy)]
 struct Registers {
@@ -34,6 +34,30 @@ enum OpCode {
     cdv(Combo),
 }
 
+impl Display for Combo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Combo::Literal(x) => f.pad(&x.to_string()),
+            Combo::Register(register) => f.pad(&format!("{:?}", register)),
+        }
+    }
+}
+
+impl Display for OpCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpCode::adv(combo) => write!(f, "adv {:<4}A = A >> {}", combo, combo),
+            OpCode::bxl(literal) => write!(f, "bxl {:<4}B = B ^ {}", literal, literal),
+            OpCode::bst(combo) => write!(f, "bst {:<4}B = {} % 8", combo, combo),
+            OpCode::jnz(literal) => write!(f, "jnz {:<4}if A != 0 jump to {}", literal, literal),
+            OpCode::bxc => write!(f, "bxc     B = B ^ C"),
+            OpCode::out(combo) => write!(f, "out {:<4}output {} % 8", combo, combo),
+            OpCode::bdv(combo) => write!(f, "bdv {:<4}B = A >> {}", combo, combo),
+            OpCode::cdv(combo) => write!(f, "cdv {:<4}C = A >> {}", combo, combo),
+        }
+    }
+}
+
 fn parse_register(line: &str) -> u64 {
     line[12..].parse::<u64>().unwrap()
 }
@@ -83,15 +107,10 @@ fn combo_value(combo: &Combo, regs: &Registers) -> u64 {
 fn execute_program(program: &[OpCode], regs: &mut Registers) -> String {
     let mut ip = 0;
     let mut outputs = vec![];
-    loop {
-        let op_code = match program.get(ip / 2) {
-            Some(op_code) => op_code,
-            None => break,
-        };
-
+    while let Some(op_code) = program.get(ip / 2) {
         match op_code {
             OpCode::adv(combo) => {
-                let denominator = combo_value(combo, &regs) as u32;
+                let denominator = combo_value(combo, regs) as u32;
                 let v = regs.A / 2u64.pow(denominator);
                 regs.A = v;
             }
@@ -99,7 +118,7 @@ fn execute_program(program: &[OpCode], regs: &mut Registers) -> String {
                 regs.B.bitxor_assign(literal);
             }
             OpCode::bst(combo) => {
-                let v = combo_value(combo, &regs) % 8;
+                let v = combo_value(combo, regs) % 8;
                 regs.B = v;
             }
             OpCode::jnz(literal) => {
@@ -111,16 +130,16 @@ fn execute_program(program: &[OpCode], regs: &mut Registers) -> String {
                 regs.B.bitxor_assign(regs.C);
             }
             OpCode::out(combo) => {
-                let value = combo_value(combo, &regs) % 8;
+                let value = combo_value(combo, regs) % 8;
                 outputs.push(value.to_string());
             }
             OpCode::bdv(combo) => {
-                let denominator = combo_value(combo, &regs) as u32;
+                let denominator = combo_value(combo, regs) as u32;
                 let v = regs.A / 2u64.pow(denominator);
                 regs.B = v;
             }
             OpCode::cdv(combo) => {
-                let denominator = combo_value(combo, &regs) as u32;
+                let denominator = combo_value(combo, regs) as u32;
                 let v = regs.A / 2u64.pow(denominator);
                 regs.C = v;
             }
@@ -148,7 +167,7 @@ fn find_a(program: &[OpCode], orig_regs: &Registers, program_code: &str) -> u64
                 A: a + i,
                 ..*orig_regs
             };
-            let output = execute_program(&program, &mut regs);
+            let output = execute_program(program, &mut regs);
             if program_code.ends_with(&output) {
                 break i;
             }
@@ -164,26 +183,32 @@ fn parse_input(input: &str) -> (Registers, Vec<OpCode>) {
     let B = lines.next().map(parse_register).unwrap();
     let C = lines.next().map(parse_register).unwrap();
     let regs = Registers { A, B, C };
-    let program = lines.skip(1).next().map(parse_program).unwrap();
+    let program = lines.nth(1).map(parse_program).unwrap();
     (regs, program)
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+pub fn disassemble(input: &str) -> String {
+    let (_, program) = parse_input(input);
+    program
+        .iter()
+        .enumerate()
+        .map(|(index, op_code)| format!("{:2}: {}\n", index * 2, op_code))
+        .collect()
+}
 
+pub fn part_1(input: &str) -> String {
     let (orig_regs, program) = parse_input(input);
 
     let mut regs = orig_regs;
-    let output = execute_program(&program, &mut regs);
-    println!("The program prints:\n{}", output);
+    execute_program(&program, &mut regs)
+}
 
-    let program_code = &input.lines().skip(4).next().unwrap()[9..];
-    println!("\nThe program itself: {}", program_code);
+pub fn part_2(input: &str) -> String {
+    let (orig_regs, program) = parse_input(input);
 
-    let a = find_a(&program, &regs, program_code);
-    println!("The program outputs itself if register A is set to {}.", a);
-    let mut regs = Registers { A: a, ..orig_regs };
-    println!("{}", execute_program(&program, &mut regs))
+    let program_code = &input.lines().nth(4).unwrap()[9..];
+    let a = find_a(&program, &orig_regs, program_code);
+    a.to_string()
 }
 
 #[cfg(test)]
@@ -238,4 +263,12 @@ mod tests {
         execute_program(&program, &mut regs);
         assert_eq!(44354, regs.B);
     }
+
+    #[test]
+    fn test_disassemble() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/demo_1.asm"),
+            &disassemble(include_str!("../input/demo_1.txt")),
+        );
+    }
 }
@@ -0,0 +1,9 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!("The program prints:\n{}", day_2024_17::part_1(input));
+    println!(
+        "The program outputs itself if register A is set to {}.",
+        day_2024_17::part_2(input)
+    );
+}
@@ -37,7 +37,7 @@ impl Map {
     }
 
     fn neighbors<'a>(&'a self, position: &'a XY) -> impl Iterator<Item = XY> + use<'a> {
-        let x = iter::successors(Some(Direction::Left), |direction| match direction {
+        iter::successors(Some(Direction::Left), |direction| match direction {
             Direction::Left => Some(Direction::Right),
             Direction::Right => Some(Direction::Up),
             Direction::Up => Some(Direction::Down),
@@ -62,8 +62,7 @@ impl Map {
             }),
             _ => None,
         })
-        .filter(|neighbor| !self.corrupted.contains(neighbor));
-        x
+        .filter(|neighbor| !self.corrupted.contains(neighbor))
     }
 }
 
@@ -150,24 +149,33 @@ fn is_path(map: &Map) -> bool {
     false
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+fn nb_bytes(bytes: &[XY]) -> usize {
+    if bytes.len() < 100 {
+        12
+    } else {
+        1024
+    }
+}
+
+pub fn part_1(input: &str) -> String {
     let bytes = parse_input(input);
-    let nb_bytes = if bytes.len() < 100 { 12 } else { 1024 };
-    let mut map = Map::new(&bytes[..nb_bytes]);
+    let nb_bytes = nb_bytes(&bytes);
+    let map = Map::new(&bytes[..nb_bytes]);
 
     let path = find_path(&map);
-    println!(
-        "The shortest path after {} fallen bytes is {} steps long.",
-        nb_bytes,
-        path.unwrap().len() - 1
-    );
+    (path.unwrap().len() - 1).to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let bytes = parse_input(input);
+    let nb_bytes = nb_bytes(&bytes);
+    let mut map = Map::new(&bytes[..nb_bytes]);
 
     for byte in &bytes[nb_bytes..] {
         map.corrupted.push(*byte);
         if !is_path(&map) {
-            println!("After byte {}, the end is not reachable anymore.", byte);
-            break;
+            return byte.to_string();
         }
     }
+    panic!("The end is reachable after all bytes have fallen.")
 }
@@ -0,0 +1,12 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "The shortest path after the fallen bytes is {} steps long.",
+        day_2024_18::part_1(input)
+    );
+    println!(
+        "After byte {}, the end is not reachable anymore.",
+        day_2024_18::part_2(input)
+    );
+}
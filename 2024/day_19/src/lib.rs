@@ -5,7 +5,7 @@ type Pattern<'a> = &'a str;
 type Towels<'a> = Vec<Towel<'a>>;
 type Patterns<'a> = Vec<Pattern<'a>>;
 
-fn parse_input(input: &str) -> (Towels, Patterns) {
+fn parse_input(input: &str) -> (Towels<'_>, Patterns<'_>) {
     let mut lines = input.lines();
     let towels = lines
         .next()
@@ -43,23 +43,26 @@ fn valid_patterns<'a>(
 
     let mut value = 0;
     for towel in towels {
-        if pattern.starts_with(towel) {
-            value += valid_patterns(&&pattern[towel.len()..], towels, cache);
+        if let Some(rest) = pattern.strip_prefix(towel) {
+            value += valid_patterns(&rest, towels, cache);
         }
     }
-    cache.insert(&pattern, value);
+    cache.insert(pattern, value);
     value
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+pub fn part_1(input: &str) -> String {
     let (towels, patterns) = parse_input(input);
 
     let possible_patterns = patterns
         .iter()
         .filter(|pattern| is_pattern_possible(pattern, &towels))
         .collect::<Vec<_>>();
-    println!("There are {} valid patterns.", possible_patterns.len());
+    possible_patterns.len().to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let (towels, patterns) = parse_input(input);
 
     let mut cache = HashMap::new();
 
@@ -67,8 +70,5 @@ fn main() {
         .iter()
         .map(|pattern| valid_patterns(pattern, &towels, &mut cache))
         .sum::<usize>();
-    println!(
-        "There are {} different patterns possible.",
-        different_patterns
-    );
+    different_patterns.to_string()
 }
@@ -0,0 +1,9 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!("There are {} valid patterns.", day_2024_19::part_1(input));
+    println!(
+        "There are {} different patterns possible.",
+        day_2024_19::part_2(input)
+    );
+}
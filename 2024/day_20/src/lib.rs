@@ -119,8 +119,7 @@ fn find_path(maze: &Maze) -> Vec<XY> {
         let next = current
             .neighbors()
             .filter(|neighbor| maze.get(neighbor).unwrap() == &Tile::Track)
-            .filter(|neighbor| !path.contains(neighbor))
-            .next()
+            .find(|neighbor| !path.contains(neighbor))
             .unwrap();
         current = next;
         path.push(current);
@@ -128,18 +127,21 @@ fn find_path(maze: &Maze) -> Vec<XY> {
     path
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
-    let maze = Maze::from_str(&input).unwrap();
-    let path = find_path(&maze);
+fn distances(path: &[XY]) -> HashMap<XY, usize> {
     let length = path.len();
-    let distances = path
-        .iter()
+    path.iter()
         .enumerate()
         .map(|(step, xy)| (*xy, length - step - 1))
-        .collect::<HashMap<_, _>>();
+        .collect::<HashMap<_, _>>()
+}
+
+const MIN_SAVE: usize = 100;
 
-    let min_save = 100;
+pub fn part_1(input: &str) -> String {
+    let maze = Maze::from_str(input).unwrap();
+    let path = find_path(&maze);
+    let distances = distances(&path);
+    let min_save = MIN_SAVE;
 
     let cheat_count = path
         .iter()
@@ -162,13 +164,17 @@ fn main() {
                 }
             })
         })
-        .filter_map(|x| x)
+        .flatten()
         .filter(|size| *size >= min_save)
         .count();
-    println!(
-        "There are {} cheats through the walls for at least 100ps.",
-        cheat_count
-    );
+    cheat_count.to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let maze = Maze::from_str(input).unwrap();
+    let path = find_path(&maze);
+    let distances = distances(&path);
+    let min_save = MIN_SAVE;
 
     let cheat_count = path
         .iter()
@@ -201,8 +207,5 @@ fn main() {
         })
         .filter(|size| *size >= min_save)
         .count();
-    println!(
-        "If real cheating is allowed, there are {} cheats for at least 100ps.",
-        cheat_count
-    );
+    cheat_count.to_string()
 }
@@ -0,0 +1,12 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "There are {} cheats through the walls for at least 100ps.",
+        day_2024_20::part_1(input)
+    );
+    println!(
+        "If real cheating is allowed, there are {} cheats for at least 100ps.",
+        day_2024_20::part_2(input)
+    );
+}
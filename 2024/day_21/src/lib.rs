@@ -97,7 +97,7 @@ fn from_to<T: Button>(from: &T, to: &T) -> Vec<Vec<Command>> {
             }
         }
         for (option, command) in Command::options()
-            .filter_map(|command| current.go(&command).and_then(|next| Some((next, command))))
+            .filter_map(|command| current.go(&command).map(|next| (next, command)))
         {
             let mut new_path = path.clone();
             new_path.push(command);
@@ -142,7 +142,7 @@ where
     cache: HashMap<(B, B), usize>,
 }
 
-fn next<K: Keypad, B: Button>(n: K) -> IndirectInput<K, B>
+fn next<K, B: Button>(n: K) -> IndirectInput<K, B>
 where
     K: Keypad<ButtonT = Command>,
 {
@@ -165,8 +165,7 @@ where
                 let to = x.1;
                 *self.cache.entry((*from, *to)).or_insert_with(|| {
                     let paths = from_to(from, to);
-                    let y = paths.iter().map(|path| self.next.cost(path)).min().unwrap();
-                    y
+                    paths.iter().map(|path| self.next.cost(path)).min().unwrap()
                 })
             })
             .sum()
@@ -176,14 +175,12 @@ where
 }
 
 fn numeric_part(code: &[NumericKeypadButton]) -> usize {
-    let numeric_part = code
-        .iter()
+    code.iter()
         .filter_map(|button| match button {
             NumericKeypadButton::Digit(digit) => Some(*digit),
             NumericKeypadButton::Activate => None,
         })
-        .fold(0, |acc, digit| acc * 10 + digit as usize);
-    numeric_part
+        .fold(0, |acc, digit| acc * 10 + digit as usize)
 }
 
 fn parse_code(code: &str) -> Vec<NumericKeypadButton> {
@@ -195,8 +192,25 @@ fn parse_code(code: &str) -> Vec<NumericKeypadButton> {
         .collect()
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+fn complexity<K>(codes: &[Vec<NumericKeypadButton>], keypad: &mut K) -> usize
+where
+    K: Keypad<ButtonT = NumericKeypadButton>,
+{
+    codes
+        .iter()
+        .map(|code| keypad.cost(code) * numeric_part(code))
+        .sum::<usize>()
+}
+
+pub fn part_1(input: &str) -> String {
+    let codes = input.lines().map(parse_code).collect::<Vec<_>>();
+
+    let mut numeric_keypad = next(next(next(DirectInput {})));
+
+    complexity(&codes, &mut numeric_keypad).to_string()
+}
+
+pub fn part_2(input: &str) -> String {
     let codes = input.lines().map(parse_code).collect::<Vec<_>>();
 
     let mut numeric_keypad = next(next(next(next(next(next(next(next(next(next(next(
@@ -205,14 +219,5 @@ fn main() {
         ))))))))))),
     )))))))))));
 
-    let x = codes
-        .iter()
-        .map(|code| {
-            let cost = numeric_keypad.cost(code);
-            let numeric_part = numeric_part(code);
-            println!("cost: {cost} * numeric: {numeric_part}");
-            cost * numeric_part
-        })
-        .sum::<usize>();
-    println!("{}", x);
+    complexity(&codes, &mut numeric_keypad).to_string()
 }
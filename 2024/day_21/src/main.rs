@@ -0,0 +1,12 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "The sum of the complexities with two robots is {}.",
+        day_2024_21::part_1(input)
+    );
+    println!(
+        "The sum of the complexities with 25 robots is {}.",
+        day_2024_21::part_2(input)
+    );
+}
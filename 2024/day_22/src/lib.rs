@@ -15,18 +15,18 @@ fn last_digit(number: u64) -> i8 {
 fn next(secret_number: u64) -> u64 {
     let number = prune(mix(secret_number, secret_number * 64));
     let number = prune(mix(number, number / 32));
-    let number = prune(mix(number, number * 2048));
-    number
+    prune(mix(number, number * 2048))
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
-    let init_numbers = input
+fn parse_input(input: &str) -> Vec<u64> {
+    input
         .lines()
         .map(|line| line.parse::<u64>().unwrap())
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
 
-    let secret_numbers = init_numbers
+fn secret_numbers(init_numbers: &[u64]) -> Vec<Vec<u64>> {
+    init_numbers
         .iter()
         .map(|number| {
             (0..2000)
@@ -36,13 +36,23 @@ fn main() {
                 })
                 .collect::<Vec<_>>()
         })
-        .collect::<Vec<_>>();
+        .collect::<Vec<_>>()
+}
+
+pub fn part_1(input: &str) -> String {
+    let init_numbers = parse_input(input);
+    let secret_numbers = secret_numbers(&init_numbers);
 
     let sum_secret_numbers = secret_numbers
         .iter()
         .map(|numbers| numbers.last().unwrap())
         .sum::<u64>();
-    println!("{}", sum_secret_numbers);
+    sum_secret_numbers.to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let init_numbers = parse_input(input);
+    let secret_numbers = secret_numbers(&init_numbers);
 
     let last_digits = secret_numbers
         .iter()
@@ -61,7 +71,7 @@ fn main() {
             iter::once(&last_digit(*init))
                 .chain(digits.iter())
                 .scan(0, |last, digit| {
-                    let digit = *digit as i8;
+                    let digit = *digit;
                     let difference = digit - *last;
                     *last = digit;
                     Some(difference)
@@ -84,14 +94,11 @@ fn main() {
                 .zip(last_digits.iter())
                 .filter_map(|(diff, digits)| {
                     let pos = diff.windows(4).position(|d| d == *window);
-                    match pos {
-                        Some(pos) => Some(digits[pos + 3] as u64),
-                        None => None,
-                    }
+                    pos.map(|pos| digits[pos + 3] as u64)
                 })
                 .sum::<u64>()
         })
         .max()
         .unwrap();
-    println!("{}", max)
+    max.to_string()
 }
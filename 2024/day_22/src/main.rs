@@ -0,0 +1,12 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "The sum of the 2000th secret numbers is {}.",
+        day_2024_22::part_1(input)
+    );
+    println!(
+        "The most bananas you can get is {}.",
+        day_2024_22::part_2(input)
+    );
+}
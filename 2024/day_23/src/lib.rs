@@ -52,17 +52,16 @@ fn computers(connections: &[Group]) -> Computers {
         computers
             .entry(a.clone())
             .and_modify(|group: &mut Group| group.add(b))
-            .or_insert(Group::new(&[b.clone()]));
+            .or_insert(Group::new(std::slice::from_ref(b)));
         computers
             .entry(b.clone())
             .and_modify(|group: &mut Group| group.add(a))
-            .or_insert(Group::new(&[a.clone()]));
+            .or_insert(Group::new(std::slice::from_ref(a)));
     });
     computers
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+pub fn part_1(input: &str) -> String {
     let connections = parse(input);
     let computers = computers(&connections);
 
@@ -78,10 +77,12 @@ fn main() {
             }
         }
     }
-    println!(
-        "There are {} sets with three connected computers, where at least one starts with a 't'.",
-        sets.len()
-    );
+    sets.len().to_string()
+}
+
+pub fn part_2(input: &str) -> String {
+    let connections = parse(input);
+    let computers = computers(&connections);
 
     let mut longest_set = Group::new(&[]);
     for (computer, group) in &computers {
@@ -102,5 +103,5 @@ fn main() {
             longest_set = set;
         }
     }
-    println!("The largest group is {}.", longest_set.password())
+    longest_set.password()
 }
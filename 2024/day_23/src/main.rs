@@ -0,0 +1,9 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "There are {} sets with three connected computers, where at least one starts with a 't'.",
+        day_2024_23::part_1(input)
+    );
+    println!("The largest group is {}.", day_2024_23::part_2(input))
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Write};
 
 trait Operation {
     fn exec(&self, b1: bool, b2: bool) -> bool;
@@ -68,12 +68,17 @@ fn z_resolve(wires: &HashMap<String, Wire>) -> u64 {
         .fold(0, |total, (index, value)| total + (value << index))
 }
 
-fn print_dot_script(wires: &HashMap<String, Wire>) {
-    println!("digraph {}", '{');
-    for (name, wire) in wires {
-        let op = match wire {
+pub fn dot_script(input: &str) -> String {
+    let wires = parse(input);
+    let mut names = wires.keys().collect::<Vec<_>>();
+    names.sort();
+
+    let mut script = String::new();
+    writeln!(script, "digraph {{").unwrap();
+    for name in names {
+        let op = match &wires[name] {
             Wire::Gate { i1, i2, op } => {
-                println!("{} -> {};\n{} -> {};", i1, name, i2, name);
+                writeln!(script, "{} -> {};\n{} -> {};", i1, name, i2, name).unwrap();
                 Some(op)
             }
             Wire::Value(_) => None,
@@ -84,33 +89,49 @@ fn print_dot_script(wires: &HashMap<String, Wire>) {
             Some(Op::Xor) => "shape=star",
             None => "",
         };
-        match name.chars().nth(0).unwrap() {
-            'x' => println!(
+        match name.chars().next().unwrap() {
+            'x' => writeln!(
+                script,
                 "{} [color=lightblue,style=filled,group=x,{}];",
                 name, op_string
             ),
-            'y' => println!(
+            'y' => writeln!(
+                script,
                 "{} [color=lightgreen,style=filled,group=y,{}];",
                 name, op_string
             ),
-            'z' => println!("{} [color=red,style=filled,group=z,{}];", name, op_string),
-            _ => println!("{} [{}];", name, op_string),
+            'z' => writeln!(
+                script,
+                "{} [color=red,style=filled,group=z,{}];",
+                name, op_string
+            ),
+            _ => writeln!(script, "{} [{}];", name, op_string),
         }
+        .unwrap();
     }
-    println!("{}", '}');
+    writeln!(script, "}}").unwrap();
+    script
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+pub fn print_dot_script(input: &str) {
+    print!("{}", dot_script(input));
+}
+
+pub fn part_1(input: &str) -> String {
     let wires = parse(input);
     let z_value = z_resolve(&wires);
-    println!("The produces number is {}.", z_value);
+    z_value.to_string()
+}
 
-    // Solution was solved visually:
-    // The created dot-script is executed and the image is analysed.
-    // - All z-fields (except the last) must be an XOR
-    // - Every irregularity indicates a mismatch. Other gate is in proximity.
-    // Sorting the affected gates is easy done manually.
-    // The solution for the input is "gqp,hsw,jmh,mwk,qgd,z10,z18,z33"
-    print_dot_script(&wires);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot_script() {
+        snapshot::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/input/snapshots/demo.dot"),
+            &dot_script(include_str!("../input/demo.txt")),
+        );
+    }
 }
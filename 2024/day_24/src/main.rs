@@ -0,0 +1,13 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!("The produces number is {}.", day_2024_24::part_1(input));
+
+    // Solution was solved visually:
+    // The created dot-script is executed and the image is analysed.
+    // - All z-fields (except the last) must be an XOR
+    // - Every irregularity indicates a mismatch. Other gate is in proximity.
+    // Sorting the affected gates is easy done manually.
+    // The solution for the input is "gqp,hsw,jmh,mwk,qgd,z10,z18,z33"
+    day_2024_24::print_dot_script(input);
+}
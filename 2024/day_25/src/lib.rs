@@ -39,13 +39,12 @@ impl Lock {
     }
 }
 
-fn main() {
-    let input = include_str!("../input/input.txt");
+pub fn part_1(input: &str) -> String {
     let (keys, locks) = parse(input);
 
     let matches = keys
         .iter()
         .map(|key| locks.iter().filter(|lock| lock.matches(key)).count())
         .sum::<usize>();
-    println!("There are {} lock/key pairs fitting together.", matches)
+    matches.to_string()
 }
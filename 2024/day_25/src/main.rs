@@ -0,0 +1,8 @@
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!(
+        "There are {} lock/key pairs fitting together.",
+        day_2024_25::part_1(input)
+    );
+}
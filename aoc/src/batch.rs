@@ -0,0 +1,230 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{
+    runner::{self, Outcome},
+    years::{Day, Year},
+};
+
+pub const ANSWERS_FILE_NAME: &str = "answers.txt";
+
+/// Known answers of a day, read from the `answers.txt` next to the `input` directory.
+///
+/// Every line holds the input file name, the part and the answer, separated by whitespace.
+/// `#` starts a comment.
+struct Answers(HashMap<(String, u8), String>);
+
+impl Answers {
+    fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                Self::parse(&content).map_err(|error| format!("{}: {}", path.display(), error))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self(HashMap::new())),
+            Err(error) => Err(format!("{}: {}", path.display(), error)),
+        }
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut fields = line.split_ascii_whitespace();
+            let (Some(file), Some(part), Some(answer), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(format!("line {}: expected 'FILE PART ANSWER'", index + 1));
+            };
+            let part = part
+                .parse::<u8>()
+                .ok()
+                .filter(|part| (1..=2).contains(part))
+                .ok_or(format!("line {}: invalid part '{}'", index + 1, part))?;
+            answers.insert((file.to_string(), part), answer.to_string());
+        }
+        Ok(Self(answers))
+    }
+
+    fn get(&self, file: &str, part: u8) -> Option<&str> {
+        self.0
+            .get(&(file.to_string(), part))
+            .map(|answer| answer.as_str())
+    }
+}
+
+fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .filter(|path| path.as_ref().map_or(true, |path| path.is_file()))
+        .collect::<io::Result<Vec<_>>>()?;
+    files.sort();
+    Ok(files)
+}
+
+/// The text of a cell and whether it passes. A part which times out or panics fails even if
+/// its answer is not known.
+fn cell(outcome: &Outcome, expected: Option<&str>) -> (String, bool) {
+    match (outcome, expected) {
+        (Outcome::Answer { answer, .. }, Some(expected)) if answer == expected => {
+            (format!("{} (ok)", answer), true)
+        }
+        (Outcome::Answer { answer, .. }, Some(expected)) => {
+            (format!("{} (expected {})", answer, expected), false)
+        }
+        (Outcome::Answer { answer, .. }, None) => (answer.clone(), true),
+        (outcome, Some(expected)) => (format!("{} (expected {})", outcome, expected), false),
+        (outcome, None) => (outcome.to_string(), false),
+    }
+}
+
+/// Runs every part of a day on one input file. Returns the cells of its row, starting with the
+/// file name, and whether all of them pass. A file which cannot be read fails.
+fn run_file(path: &Path, day: &Day, answers: &Answers, timeout: Duration) -> (Vec<String>, bool) {
+    let file = path.file_name().unwrap().to_string_lossy().to_string();
+    let mut row = vec![file.clone()];
+    let input = match fs::read_to_string(path) {
+        Ok(input) => Arc::<str>::from(input),
+        Err(error) => {
+            row.push(format!("cannot read: {}", error));
+            return (row, false);
+        }
+    };
+    let mut correct = true;
+    for (number, part) in day.parts() {
+        let outcome = runner::run_part(part, input.clone(), timeout);
+        let (text, ok) = cell(&outcome, answers.get(&file, number));
+        correct &= ok;
+        row.push(text);
+    }
+    (row, correct)
+}
+
+/// Runs every part of a day on every file of its input directory and prints the answers as a
+/// matrix. Returns `false` if an answer differs from the known one or a part does not finish.
+pub fn run_day(year: &Year, day: &Day, timeout: Duration) -> bool {
+    println!("{} day {:02}", year.number, day.number);
+
+    let answers = match Answers::load(&year.day_dir(day).join(ANSWERS_FILE_NAME)) {
+        Ok(answers) => answers,
+        Err(error) => {
+            println!("  Cannot read the answers {}", error);
+            return false;
+        }
+    };
+    let files = match input_files(&year.input_dir(day)) {
+        Ok(files) => files,
+        Err(error) => {
+            println!("  Cannot list the input files: {}", error);
+            return false;
+        }
+    };
+
+    let mut header = vec!["File".to_string()];
+    header.extend(day.parts().map(|(number, _)| format!("Part {}", number)));
+    let mut rows = vec![header];
+    let mut correct = true;
+
+    for path in files {
+        let (row, ok) = run_file(&path, day, &answers, timeout);
+        correct &= ok;
+        rows.push(row);
+    }
+
+    let columns = rows.iter().map(|row| row.len()).max().unwrap();
+    let widths = (0..columns)
+        .map(|column| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(|text| text.chars().count())
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(text, width)| format!("{:width$}", text, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("  {}", line.trim_end());
+    }
+    correct
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers =
+            Answers::parse("# file part answer\ndemo.txt 1 140\n\ndemo.txt 2 80 # sides\n")
+                .unwrap();
+        assert_eq!(Some("140"), answers.get("demo.txt", 1));
+        assert_eq!(Some("80"), answers.get("demo.txt", 2));
+        assert_eq!(None, answers.get("input.txt", 1));
+    }
+
+    #[test]
+    fn test_cell() {
+        let answer = Outcome::Answer {
+            answer: "140".to_string(),
+            elapsed: Duration::ZERO,
+        };
+        assert_eq!(("140 (ok)".to_string(), true), cell(&answer, Some("140")));
+        assert_eq!(("140".to_string(), true), cell(&answer, None));
+        assert_eq!(
+            ("timeout".to_string(), false),
+            cell(&Outcome::Timeout, None)
+        );
+        assert_eq!(
+            ("panicked: oops".to_string(), false),
+            cell(&Outcome::Panicked("oops".to_string()), None)
+        );
+    }
+
+    #[test]
+    fn test_run_file() {
+        let day = Day {
+            number: 1,
+            part_1: |input| input.len().to_string(),
+            part_2: None,
+        };
+        let answers = Answers::parse("demo.txt 1 3").unwrap();
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let readable = dir.join("demo.txt");
+        fs::write(&readable, "abc").unwrap();
+        let unreadable = dir.join("binary.txt");
+        fs::write(&unreadable, [0xff, 0xfe]).unwrap();
+        let timeout = Duration::from_secs(10);
+
+        let (row, ok) = run_file(&readable, &day, &answers, timeout);
+        assert_eq!(vec!["demo.txt", "3 (ok)"], row);
+        assert!(ok);
+        let (row, ok) = run_file(&unreadable, &day, &answers, timeout);
+        assert_eq!("binary.txt", row[0]);
+        assert!(row[1].starts_with("cannot read: "));
+        assert!(!ok);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_answers_error() {
+        assert_eq!(
+            "line 2: invalid part '3'",
+            Answers::parse("demo.txt 1 140\ndemo.txt 3 80")
+                .err()
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use std::{fs, io, path::Path, time::Duration};
+
+pub const FILE_NAME: &str = "aoc.conf";
+
+/// Settings of a year, read from the `aoc.conf` in the directory of the year.
+///
+/// The file consists of `key = value` lines, `#` starts a comment.
+pub struct Config {
+    pub timeout: Duration,
+    pub input: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            input: "input.txt".to_string(),
+        }
+    }
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                Self::parse(&content).map_err(|error| format!("{}: {}", path.display(), error))
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("{}: {}", path.display(), error)),
+        }
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        for (index, line) in content.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {}: expected 'key = value'", index + 1))?;
+            let value = value.trim();
+            match key.trim() {
+                "timeout" => {
+                    config.timeout = parse_timeout(value).ok_or(format!(
+                        "line {}: invalid timeout '{}'",
+                        index + 1,
+                        value
+                    ))?;
+                }
+                "input" => config.input = value.to_string(),
+                key => return Err(format!("line {}: unknown key '{}'", index + 1, key)),
+            }
+        }
+        Ok(config)
+    }
+}
+
+pub fn parse_timeout(seconds: &str) -> Option<Duration> {
+    seconds
+        .parse::<f64>()
+        .ok()
+        .filter(|seconds| *seconds > 0.0)
+        .map(Duration::from_secs_f64)
+}
@@ -0,0 +1,191 @@
+use std::{env, fs, panic, path::PathBuf, process, sync::Arc, time::Duration};
+
+use config::Config;
+use years::{Day, Year};
+
+mod batch;
+mod config;
+mod runner;
+mod years;
+
+const USAGE: &str = "Usage: aoc run [YEAR | YEAR/DAY | DAY]... [--timeout SECONDS] [--input FILE]
+       aoc batch [YEAR | YEAR/DAY | DAY]... [--timeout SECONDS]
+       aoc accept [YEAR | YEAR/DAY | DAY]...";
+
+#[derive(PartialEq)]
+enum Command {
+    Run,
+    Batch,
+    Accept,
+}
+
+struct Options {
+    command: Command,
+    days: Vec<(&'static Year, &'static Day)>,
+    timeout: Option<Duration>,
+    input: Option<PathBuf>,
+}
+
+fn parse_selection(selection: &str) -> Option<Vec<(&'static Year, &'static Day)>> {
+    let (year, day) = match selection.split_once('/') {
+        Some((year, day)) => (years::find(year.parse().ok()?)?, Some(day)),
+        None if selection.len() == 4 => (years::find(selection.parse().ok()?)?, None),
+        None => (years::latest(), Some(selection)),
+    };
+    match day {
+        Some(day) => {
+            let day = year.find_day(day.parse().ok()?)?;
+            Some(vec![(year, day)])
+        }
+        None => Some(year.days.iter().map(|day| (year, day)).collect()),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("batch") => Command::Batch,
+        Some("accept") => Command::Accept,
+        Some(command) => return Err(format!("Unknown command '{}'.", command)),
+        None => return Err("No command given.".to_string()),
+    };
+
+    let mut options = Options {
+        command,
+        days: vec![],
+        timeout: None,
+        input: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--timeout" => {
+                let timeout = args
+                    .next()
+                    .and_then(|seconds| config::parse_timeout(&seconds))
+                    .ok_or("--timeout expects a positive number of seconds.")?;
+                options.timeout = Some(timeout);
+            }
+            "--input" if options.command == Command::Run => {
+                let input = args.next().ok_or("--input expects a file.")?;
+                options.input = Some(PathBuf::from(input));
+            }
+            "--input" => return Err("--input is only supported by run.".to_string()),
+            selection => {
+                let days = parse_selection(selection)
+                    .ok_or(format!("There is no puzzle '{}'.", selection))?;
+                options.days.extend(days);
+            }
+        }
+    }
+
+    if options.days.is_empty() {
+        if options.input.is_some() {
+            return Err("--input needs exactly one day.".to_string());
+        }
+        options.days = years::YEARS
+            .iter()
+            .flat_map(|year| year.days.iter().map(move |day| (year, day)))
+            .collect();
+    }
+    if options.input.is_some() && options.days.len() > 1 {
+        return Err("--input needs exactly one day.".to_string());
+    }
+    Ok(options)
+}
+
+fn load_config(year: &Year) -> Option<Config> {
+    match Config::load(&year.dir().join(config::FILE_NAME)) {
+        Ok(config) => Some(config),
+        Err(error) => {
+            println!("  Cannot read the configuration {}", error);
+            None
+        }
+    }
+}
+
+fn run_day(year: &Year, day: &Day, options: &Options) {
+    println!("{} day {:02}", year.number, day.number);
+
+    let Some(config) = load_config(year) else {
+        return;
+    };
+    let timeout = options.timeout.unwrap_or(config.timeout);
+    let path = options
+        .input
+        .clone()
+        .unwrap_or_else(|| year.input_dir(day).join(&config.input));
+    let input: Arc<str> = match fs::read_to_string(&path) {
+        Ok(input) => Arc::from(input),
+        Err(error) => {
+            println!("  Cannot read {}: {}", path.display(), error);
+            return;
+        }
+    };
+
+    for (number, part) in day.parts() {
+        let outcome = runner::run_part(part, input.clone(), timeout);
+        println!("  Part {}: {}", number, outcome);
+    }
+}
+
+fn accept_snapshots(year: &Year, day: &Day) -> bool {
+    match snapshot::accept(&year.input_dir(day).join("snapshots")) {
+        Ok(accepted) => {
+            for path in accepted {
+                println!("Accepted {}", path.display());
+            }
+            true
+        }
+        Err(error) => {
+            println!(
+                "Cannot accept the snapshots of {} day {:02}: {}",
+                year.number, day.number, error
+            );
+            false
+        }
+    }
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    // Panics are reported per part, the default hook would only clutter the output.
+    panic::set_hook(Box::new(|_| {}));
+
+    match options.command {
+        Command::Run => {
+            for (year, day) in &options.days {
+                run_day(year, day, &options);
+            }
+        }
+        Command::Batch => {
+            let mut correct = true;
+            for (year, day) in &options.days {
+                let Some(config) = load_config(year) else {
+                    correct = false;
+                    continue;
+                };
+                let timeout = options.timeout.unwrap_or(config.timeout);
+                correct &= batch::run_day(year, day, timeout);
+            }
+            if !correct {
+                process::exit(1);
+            }
+        }
+        Command::Accept => {
+            let mut accepted = true;
+            for (year, day) in &options.days {
+                accepted &= accept_snapshots(year, day);
+            }
+            if !accepted {
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::years::Part;
+
+// The solutions recurse deeply in a few places, the default of 2 MiB is not enough.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+pub enum Outcome {
+    Answer { answer: String, elapsed: Duration },
+    Timeout,
+    Panicked(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Answer { answer, elapsed } => write!(f, "{} ({:.2?})", answer, elapsed),
+            Outcome::Timeout => write!(f, "timeout"),
+            Outcome::Panicked(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Runs a single part on its own thread.
+///
+/// A thread cannot be killed, so a part running into the timeout keeps running in the
+/// background until the process exits.
+pub fn run_part(part: Part, input: Arc<str>, timeout: Duration) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let start = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| part(&input)));
+            let _ = sender.send(result.map(|answer| (answer, start.elapsed())));
+        });
+    if let Err(error) = spawned {
+        return Outcome::Panicked(error.to_string());
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok((answer, elapsed))) => Outcome::Answer { answer, elapsed },
+        Ok(Err(payload)) => Outcome::Panicked(panic_message(payload)),
+        Err(mpsc::RecvTimeoutError::Timeout) => Outcome::Timeout,
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            Outcome::Panicked("thread ended without a result".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(part: Part) -> Outcome {
+        run_part(part, Arc::from("input"), Duration::from_millis(200))
+    }
+
+    #[test]
+    fn test_answer() {
+        let outcome = run(|input| input.to_uppercase());
+        assert!(matches!(outcome, Outcome::Answer { answer, .. } if answer == "INPUT"));
+    }
+
+    #[test]
+    fn test_panic() {
+        let outcome = run(|input| input.parse::<u32>().unwrap().to_string());
+        assert_eq!(
+            "panicked: called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit }",
+            outcome.to_string()
+        );
+    }
+
+    #[test]
+    fn test_timeout() {
+        let outcome = run(|_| loop {
+            thread::sleep(Duration::from_millis(10));
+        });
+        assert_eq!("timeout", outcome.to_string());
+    }
+}
@@ -0,0 +1,86 @@
+use std::path::{Path, PathBuf};
+
+pub type Part = fn(&str) -> String;
+
+pub struct Day {
+    pub number: u8,
+    pub part_1: Part,
+    pub part_2: Option<Part>,
+}
+
+impl Day {
+    pub fn name(&self) -> String {
+        format!("day_{:02}", self.number)
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = (u8, Part)> {
+        [(1, Some(self.part_1)), (2, self.part_2)]
+            .into_iter()
+            .filter_map(|(number, part)| part.map(|part| (number, part)))
+    }
+}
+
+pub struct Year {
+    pub number: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn dir(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join(self.number.to_string())
+    }
+
+    pub fn day_dir(&self, day: &Day) -> PathBuf {
+        self.dir().join(day.name())
+    }
+
+    pub fn input_dir(&self, day: &Day) -> PathBuf {
+        self.day_dir(day).join("input")
+    }
+
+    pub fn find_day(&self, number: u8) -> Option<&'static Day> {
+        self.days.iter().find(|day| day.number == number)
+    }
+}
+
+macro_rules! day {
+    ($number:literal, $krate:ident) => {
+        Day {
+            number: $number,
+            part_1: $krate::part_1,
+            part_2: Some($krate::part_2),
+        }
+    };
+    ($number:literal, $krate:ident, part_1) => {
+        Day {
+            number: $number,
+            part_1: $krate::part_1,
+            part_2: None,
+        }
+    };
+}
+
+macro_rules! year {
+    ($number:literal, $module:ident) => {
+        Year {
+            number: $number,
+            days: $module::DAYS,
+        }
+    };
+}
+
+// new.sh registers new years and days in these lists.
+mod y2024;
+
+pub const YEARS: &[Year] = &[year!(2024, y2024)];
+
+pub fn find(number: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|year| year.number == number)
+}
+
+pub fn latest() -> &'static Year {
+    YEARS.iter().max_by_key(|year| year.number).unwrap()
+}
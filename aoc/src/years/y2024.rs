@@ -0,0 +1,29 @@
+use super::Day;
+
+pub const DAYS: &[Day] = &[
+    day!(1, day_2024_01),
+    day!(2, day_2024_02),
+    day!(3, day_2024_03),
+    day!(4, day_2024_04),
+    day!(5, day_2024_05),
+    day!(6, day_2024_06),
+    day!(7, day_2024_07, part_1),
+    day!(8, day_2024_08),
+    day!(9, day_2024_09),
+    day!(10, day_2024_10),
+    day!(11, day_2024_11),
+    day!(12, day_2024_12),
+    day!(13, day_2024_13),
+    day!(14, day_2024_14, part_1),
+    day!(15, day_2024_15),
+    day!(16, day_2024_16),
+    day!(17, day_2024_17),
+    day!(18, day_2024_18),
+    day!(19, day_2024_19),
+    day!(20, day_2024_20),
+    day!(21, day_2024_21),
+    day!(22, day_2024_22),
+    day!(23, day_2024_23),
+    day!(24, day_2024_24, part_1),
+    day!(25, day_2024_25, part_1),
+];
@@ -1,16 +1,71 @@
 #!/usr/bin/env bash
 
-if [ "$#" -ne 1 ]; then
-    echo "No number given"
+# Usage: ./new.sh YEAR DAY or ./new.sh YEAR/DAY
+
+if [ "$#" -eq 1 ] && [[ "$1" == */* ]]; then
+    set -- "${1%%/*}" "${1##*/}"
+fi
+if [ "$#" -ne 2 ]; then
+    echo "Usage: ./new.sh YEAR DAY"
     exit
 fi
 
-today=$(printf %02d $1)
-cargo new day_$today
+year=$1
+today=$(printf %02d $((10#$2)))
+krate=day_${year}_$today
+dir=$year/day_$today
+
+if [ -e "$dir" ]; then
+    echo "$dir already exists"
+    exit
+fi
+
+cargo new --lib --name $krate $dir
+
+cat > $dir/src/lib.rs <<EOF
+pub fn part_1(_input: &str) -> String {
+    todo!()
+}
 
-yest=$(printf %02d $(($1-1)))
+pub fn part_2(_input: &str) -> String {
+    todo!()
+}
+EOF
+
+cat > $dir/src/main.rs <<EOF
+fn main() {
+    let input = include_str!("../input/input.txt");
+
+    println!("{}", $krate::part_1(input));
+    println!("{}", $krate::part_2(input));
+}
+EOF
+
+mkdir $dir/input
+touch $dir/input/demo.txt
+touch $dir/input/input.txt
+echo "# file part answer" > $dir/answers.txt
+
+# Register the day in the runner.
+echo "$krate = { path = \"../$dir\" }" >> aoc/Cargo.toml
+
+registry=aoc/src/years/y$year.rs
+if [ ! -e "$registry" ]; then
+    cat > $registry <<EOF
+use super::Day;
+
+pub const DAYS: &[Day] = &[day!($((10#$today)), $krate)];
+EOF
+    cat > $year/aoc.conf <<EOF
+# Settings of the aoc runner for the puzzles of $year.
+timeout = 30
+input = input.txt
+EOF
+    perl -0pi -e "s/(.*\nmod y\d+;)/\$1\nmod y$year;/s" aoc/src/years.rs
+    perl -0pi -e "s/(pub const YEARS: &\[Year\] = &\[.*?),?\s*\];/\$1,\n    year!($year, y$year),\n];/s" aoc/src/years.rs
+else
+    perl -0pi -e "s/(pub const DAYS: &\[Day\] = &\[.*?),?\s*\];/\$1,\n    day!($((10#$today)), $krate),\n];/s" $registry
+fi
+cargo fmt -p aoc
 
-sed -i s/day_$yest/day_$today/ .vscode/launch.json
-mkdir day_$today/input
-touch day_$today/input/demo.txt
-touch day_$today/input/input.txt
\ No newline at end of file
+sed -i "s/day_[0-9]\{4\}_[0-9]\{2\}/$krate/" .vscode/launch.json
@@ -0,0 +1,170 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Suffix of a rendering which does not match its snapshot yet.
+pub const PENDING_SUFFIX: &str = ".new";
+
+const CONTEXT: usize = 2;
+
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+fn diff_lines<'a>(expected: &'a str, actual: &'a str) -> Vec<Line<'a>> {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+
+    // Length of the longest common subsequence of the remaining lines.
+    let mut lengths = vec![vec![0; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = if expected[i] == actual[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push(Line::Same(expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < expected.len()
+            && (j == actual.len() || lengths[i + 1][j] >= lengths[i][j + 1])
+        {
+            lines.push(Line::Removed(expected[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(actual[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+/// Renders the differences between two texts line by line, `-` marks lines only in `expected`
+/// and `+` lines only in `actual`. Unchanged lines are only shown around changes.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let lines = diff_lines(expected, actual);
+    let changed = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Line::Same(_)))
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    let mut output = String::new();
+    let mut last_shown = None;
+    for (index, line) in lines.iter().enumerate() {
+        let near_change = changed
+            .iter()
+            .any(|changed| changed.abs_diff(index) <= CONTEXT);
+        if !near_change {
+            continue;
+        }
+        if last_shown.is_some_and(|last| last + 1 != index) {
+            output.push_str("  ...\n");
+        }
+        let (marker, text) = match line {
+            Line::Same(text) => (' ', text),
+            Line::Removed(text) => ('-', text),
+            Line::Added(text) => ('+', text),
+        };
+        output.push_str(&format!("{} {}\n", marker, text));
+        last_shown = Some(index);
+    }
+    output
+}
+
+fn pending_path(path: &Path) -> PathBuf {
+    let mut pending = path.as_os_str().to_owned();
+    pending.push(PENDING_SUFFIX);
+    PathBuf::from(pending)
+}
+
+/// Compares `actual` with the snapshot stored at `path`.
+///
+/// If the snapshot is missing or differs, `actual` is written next to it with the suffix
+/// `.new` and the test fails with a diff. `aoc accept` turns these files into the snapshots.
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+    let pending = pending_path(path);
+
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => Some(expected),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(error) => panic!("Cannot read the snapshot {}: {}", path.display(), error),
+    };
+    if expected.as_deref() == Some(actual) {
+        let _ = fs::remove_file(&pending);
+        return;
+    }
+
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    fs::write(&pending, actual)
+        .unwrap_or_else(|error| panic!("Cannot write {}: {}", pending.display(), error));
+    match expected {
+        Some(expected) => panic!(
+            "The rendering differs from the snapshot {}:\n{}\nThe new rendering is written to {}, accept it with `cargo run -p aoc -- accept`.",
+            path.display(),
+            diff(&expected, actual),
+            pending.display()
+        ),
+        None => panic!(
+            "There is no snapshot {} yet.\nThe rendering is written to {}, accept it with `cargo run -p aoc -- accept`.",
+            path.display(),
+            pending.display()
+        ),
+    }
+}
+
+/// Replaces the snapshots in `dir` by their pending renderings and returns the accepted
+/// snapshots.
+pub fn accept(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut accepted = vec![];
+    if !dir.is_dir() {
+        return Ok(accepted);
+    }
+    for entry in fs::read_dir(dir)? {
+        let pending = entry?.path();
+        let Some(name) = pending.to_str() else {
+            continue;
+        };
+        if let Some(snapshot) = name.strip_suffix(PENDING_SUFFIX) {
+            fs::rename(&pending, snapshot)?;
+            accepted.push(PathBuf::from(snapshot));
+        }
+    }
+    accepted.sort();
+    Ok(accepted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let expected = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let actual = "a\nb\nc\nD\ne\nf\ng\nh\ni\nj\nk\n";
+        assert_eq!(
+            "  b\n  c\n- d\n+ D\n  e\n  f\n  ...\n  i\n  j\n+ k\n",
+            diff(expected, actual)
+        );
+    }
+
+    #[test]
+    fn test_diff_equal() {
+        assert_eq!("", diff("a\nb\n", "a\nb\n"));
+    }
+}